@@ -0,0 +1,17 @@
+use kube::Client;
+
+use crate::domain::entities::HpaDetailInfo;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::autoscaling_repository;
+
+pub struct AutoscalingHandler;
+
+impl AutoscalingHandler {
+    pub async fn get_hpa_detail(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<HpaDetailInfo, DomainError> {
+        autoscaling_repository::get_hpa_detail(client, namespace, name).await
+    }
+}
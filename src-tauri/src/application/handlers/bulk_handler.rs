@@ -0,0 +1,172 @@
+use kube::{api::Patch, discovery::ApiResource};
+use serde_json::Value;
+
+use crate::application::services::labels;
+use crate::domain::entities::{BulkItemResult, MetadataEdit, ResourceRef};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::bulk_repository::{self, item_result, BulkTarget};
+use crate::infrastructure::kubernetes::client_manager::ClientManager;
+use crate::infrastructure::kubernetes::helpers::build_api_resource;
+
+/// Resolve a reference's type the same way the single-object editing commands do: a full
+/// group/version/plural is used as-is, anything else goes through discovery.
+async fn resolve_type(
+    manager: &ClientManager,
+    client: &kube::Client,
+    context: &str,
+    r: &ResourceRef,
+) -> Result<(ApiResource, bool), DomainError> {
+    let group = r.group.as_deref().unwrap_or_default();
+    if let (Some(version), Some(plural)) = (&r.version, &r.plural) {
+        return Ok((build_api_resource(group, version, &r.kind, plural), r.cluster_scoped.unwrap_or(false)));
+    }
+    let query = if group.is_empty() { r.kind.clone() } else { format!("{}.{}", r.kind, group) };
+    manager.discovery.resolve_api_resource(client, context, &query).await
+}
+
+/// Resolve every reference to a target. References whose type can't be resolved are
+/// returned as failed results instead of aborting the whole batch.
+async fn resolve_refs(
+    manager: &ClientManager,
+    client: &kube::Client,
+    context: &str,
+    default_ns: &str,
+    refs: Vec<ResourceRef>,
+) -> (Vec<BulkTarget>, Vec<BulkItemResult>) {
+    let mut targets = Vec::with_capacity(refs.len());
+    let mut failed = Vec::new();
+    for r in refs {
+        let namespace = r.namespace.clone().filter(|ns| !ns.is_empty()).unwrap_or_else(|| default_ns.to_string());
+        match resolve_type(manager, client, context, &r).await {
+            Ok((ar, cluster_scoped)) => targets.push(BulkTarget {
+                kind: r.kind,
+                name: r.name,
+                namespace: if cluster_scoped { String::new() } else { namespace },
+                ar,
+                cluster_scoped,
+            }),
+            Err(e) => failed.push(item_result(&r.kind, &r.name, &namespace, Err(e))),
+        }
+    }
+    (targets, failed)
+}
+
+/// Bulk selector deletes must be scoped; an empty selector would match every object.
+fn require_selector(label_selector: Option<&str>, field_selector: Option<&str>, reason: Option<&str>) -> Result<(), DomainError> {
+    let given = |s: Option<&str>| s.is_some_and(|s| !s.trim().is_empty());
+    if given(label_selector) || given(field_selector) || given(reason) {
+        Ok(())
+    } else {
+        Err(DomainError::Configuration("A label or field selector is required".to_string()))
+    }
+}
+
+pub struct BulkHandler;
+
+impl BulkHandler {
+    pub async fn delete_resources(manager: &ClientManager, refs: Vec<ResourceRef>) -> Result<Vec<BulkItemResult>, DomainError> {
+        let (client, ns, ctx) = manager.get_active_client_and_context().await?;
+        let (targets, failed) = resolve_refs(manager, &client, &ctx, &ns, refs).await;
+        let mut results = bulk_repository::delete_resources(&client, targets).await;
+        results.extend(failed);
+        Ok(results)
+    }
+
+    pub async fn patch_resources(
+        manager: &ClientManager,
+        refs: Vec<ResourceRef>,
+        patch_json: &Value,
+    ) -> Result<Vec<BulkItemResult>, DomainError> {
+        let (client, ns, ctx) = manager.get_active_client_and_context().await?;
+        let (targets, failed) = resolve_refs(manager, &client, &ctx, &ns, refs).await;
+        let patch = Patch::Strategic(patch_json.clone());
+        let mut results = bulk_repository::patch_resources(&client, targets, &patch).await;
+        results.extend(failed);
+        Ok(results)
+    }
+
+    /// Add, update or remove labels and annotations on each referenced object with a JSON
+    /// merge patch. Keys and values are validated up front so nothing is sent if any are bad.
+    pub async fn update_metadata(
+        manager: &ClientManager,
+        refs: Vec<ResourceRef>,
+        labels: &MetadataEdit,
+        annotations: &MetadataEdit,
+    ) -> Result<Vec<BulkItemResult>, DomainError> {
+        let patch = Patch::Merge(labels::build_metadata_patch(labels, annotations)?);
+        let (client, ns, ctx) = manager.get_active_client_and_context().await?;
+        let (targets, failed) = resolve_refs(manager, &client, &ctx, &ns, refs).await;
+        let mut results = bulk_repository::patch_resources(&client, targets, &patch).await;
+        results.extend(failed);
+        Ok(results)
+    }
+
+    pub async fn update_metadata_by_selector(
+        manager: &ClientManager,
+        kind: &str,
+        label_selector: &str,
+        all_namespaces: bool,
+        labels: &MetadataEdit,
+        annotations: &MetadataEdit,
+    ) -> Result<Vec<BulkItemResult>, DomainError> {
+        require_selector(Some(label_selector), None, None)?;
+        let patch = Patch::Merge(labels::build_metadata_patch(labels, annotations)?);
+        let (client, ns, ctx) = manager.get_active_client_and_context().await?;
+        let (ar, cluster_scoped) = manager.discovery.resolve_api_resource(&client, &ctx, kind).await?;
+        let scope = (!all_namespaces && !cluster_scoped).then_some(ns.as_str());
+        let targets =
+            bulk_repository::select_targets(&client, scope, &ar, cluster_scoped, Some(label_selector), None).await?;
+        Ok(bulk_repository::patch_resources(&client, targets, &patch).await)
+    }
+
+    pub async fn restart_workloads(manager: &ClientManager, refs: Vec<ResourceRef>) -> Result<Vec<BulkItemResult>, DomainError> {
+        let (client, ns) = manager.get_active_client().await?;
+        let workloads = refs
+            .into_iter()
+            .map(|r| (r.namespace.filter(|n| !n.is_empty()).unwrap_or_else(|| ns.clone()), r.kind, r.name))
+            .collect();
+        Ok(bulk_repository::restart_workloads(&client, workloads).await)
+    }
+
+    pub async fn delete_pods(
+        manager: &ClientManager,
+        names: Vec<String>,
+    ) -> Result<Vec<BulkItemResult>, DomainError> {
+        let (client, ns) = manager.get_active_client().await?;
+        let pods = names.into_iter().map(|name| (ns.clone(), name)).collect();
+        Ok(bulk_repository::delete_pods(&client, pods).await)
+    }
+
+    pub async fn delete_resources_by_selector(
+        manager: &ClientManager,
+        kind: &str,
+        label_selector: Option<&str>,
+        field_selector: Option<&str>,
+        all_namespaces: bool,
+    ) -> Result<Vec<BulkItemResult>, DomainError> {
+        require_selector(label_selector, field_selector, None)?;
+        let (client, ns, ctx) = manager.get_active_client_and_context().await?;
+        let (ar, cluster_scoped) = manager.discovery.resolve_api_resource(&client, &ctx, kind).await?;
+        let scope = (!all_namespaces && !cluster_scoped).then_some(ns.as_str());
+        let targets =
+            bulk_repository::select_targets(&client, scope, &ar, cluster_scoped, label_selector, field_selector).await?;
+        Ok(bulk_repository::delete_resources(&client, targets).await)
+    }
+
+    /// Delete pods by selector, optionally narrowed to a `status.reason`; e.g.
+    /// `field_selector = "status.phase=Failed"`, `reason = "Evicted"` clears evicted pods.
+    pub async fn delete_pods_by_selector(
+        manager: &ClientManager,
+        label_selector: Option<&str>,
+        field_selector: Option<&str>,
+        reason: Option<&str>,
+        all_namespaces: bool,
+    ) -> Result<Vec<BulkItemResult>, DomainError> {
+        require_selector(label_selector, field_selector, reason)?;
+        let (client, ns) = manager.get_active_client().await?;
+        let scope = (!all_namespaces).then_some(ns.as_str());
+        let reason = reason.filter(|r| !r.is_empty());
+        let pods = bulk_repository::select_pods(&client, scope, label_selector, field_selector, reason).await?;
+        Ok(bulk_repository::delete_pods(&client, pods).await)
+    }
+}
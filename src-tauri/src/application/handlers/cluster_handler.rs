@@ -1,7 +1,9 @@
+use std::collections::BTreeMap;
+
 use kube::Client;
 
 use crate::application::services::config_db::ConfigDB;
-use crate::domain::entities::{ContextInfo, NamespaceInfo};
+use crate::domain::entities::{ApiResourceInfo, ContextInfo, NamespaceDetailInfo, NamespaceInfo};
 use crate::domain::errors::DomainError;
 use crate::infrastructure::kubernetes::{client_manager::ClientManager, cluster_repository};
 
@@ -16,8 +18,41 @@ impl ClusterHandler {
         cluster_repository::list_namespaces(client).await
     }
 
+    pub async fn create_namespace(
+        client: &Client,
+        name: &str,
+        labels: &BTreeMap<String, String>,
+    ) -> Result<NamespaceInfo, DomainError> {
+        cluster_repository::create_namespace(client, name, labels).await
+    }
+
+    pub async fn delete_namespace(client: &Client, name: &str) -> Result<(), DomainError> {
+        cluster_repository::delete_namespace(client, name).await
+    }
+
+    pub async fn get_namespace_detail(manager: &ClientManager, name: &str) -> Result<NamespaceDetailInfo, DomainError> {
+        let (client, ctx) = manager.get_client_and_context().await?;
+        let resources = manager.discovery.resources(&client, &ctx).await?;
+        cluster_repository::get_namespace_detail(&client, name, &resources).await
+    }
+
     pub async fn set_context(manager: &ClientManager, name: &str) -> Result<(), DomainError> {
         manager.set_context(name).await
     }
 
+    pub async fn list_api_resources(manager: &ClientManager, refresh: bool) -> Result<Vec<ApiResourceInfo>, DomainError> {
+        let (client, ctx) = manager.get_client_and_context().await?;
+        let resources = if refresh {
+            manager.discovery.refresh(&client, &ctx).await?
+        } else {
+            manager.discovery.resources(&client, &ctx).await?
+        };
+        Ok(resources.as_ref().clone())
+    }
+
+    pub async fn resolve_api_resource(manager: &ClientManager, kind: &str) -> Result<ApiResourceInfo, DomainError> {
+        let (client, ctx) = manager.get_client_and_context().await?;
+        manager.discovery.resolve(&client, &ctx, kind).await
+    }
+
 }
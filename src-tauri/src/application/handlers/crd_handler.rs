@@ -1,10 +1,76 @@
 use kube::{Client, Api, api::ListParams};
-use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::{
+    CustomResourceDefinition, CustomResourceDefinitionVersion,
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
 use kube::api::DynamicObject;
+use serde_json::Value;
 
-use crate::domain::entities::{CRDInfo, CRDInstanceInfo};
+use crate::domain::entities::{CRDInfo, CRDInstanceInfo, CRDPrinterColumnInfo, CRDVersionInfo};
 use crate::domain::errors::DomainError;
 use crate::application::services::formatting::format_age;
+use crate::application::services::jsonpath;
+use crate::infrastructure::kubernetes::helpers::build_api_resource;
+
+/// The version to use when none is requested: the storage version if it is still served,
+/// otherwise the first served one.
+fn default_version(crd: &CustomResourceDefinition) -> Option<&CustomResourceDefinitionVersion> {
+    let versions = &crd.spec.versions;
+    versions
+        .iter()
+        .find(|v| v.storage && v.served)
+        .or_else(|| versions.iter().find(|v| v.served))
+}
+
+fn select_version<'a>(
+    crd: &'a CustomResourceDefinition,
+    version: Option<&str>,
+) -> Result<&'a CustomResourceDefinitionVersion, DomainError> {
+    let name = crd.metadata.name.clone().unwrap_or_default();
+    match version.filter(|v| !v.is_empty()) {
+        Some(requested) => crd
+            .spec
+            .versions
+            .iter()
+            .find(|v| v.name == requested && v.served)
+            .ok_or_else(|| DomainError::NotFound(format!("Version {} of {} is not served", requested, name))),
+        None => default_version(crd).ok_or_else(|| DomainError::NotFound(format!("{} has no served versions", name))),
+    }
+}
+
+fn printer_columns(version: &CustomResourceDefinitionVersion) -> Vec<CRDPrinterColumnInfo> {
+    version
+        .additional_printer_columns
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .map(|c| CRDPrinterColumnInfo {
+            name: c.name.clone(),
+            column_type: c.type_.clone(),
+            json_path: c.json_path.clone(),
+            description: c.description.clone().unwrap_or_default(),
+            priority: c.priority.unwrap_or(0),
+        })
+        .collect()
+}
+
+fn column_value(obj: &Value, column: &CRDPrinterColumnInfo) -> String {
+    let rendered = jsonpath::evaluate(obj, &column.json_path)
+        .map(|values| jsonpath::render(&values))
+        .unwrap_or_default();
+    // Date columns are shown as an age, as kubectl does
+    if column.column_type == "date" {
+        if let Ok(t) = chrono::DateTime::parse_from_rfc3339(&rendered) {
+            return format_age(Some(&Time(t.with_timezone(&chrono::Utc))));
+        }
+    }
+    rendered
+}
+
+async fn get_crd(client: &Client, group: &str, plural: &str) -> Result<CustomResourceDefinition, DomainError> {
+    let api: Api<CustomResourceDefinition> = Api::all(client.clone());
+    Ok(api.get(&format!("{}.{}", plural, group)).await?)
+}
 
 pub struct CRDHandler;
 
@@ -17,29 +83,33 @@ impl CRDHandler {
             .items
             .into_iter()
             .filter_map(|crd| {
+                let version = default_version(&crd)?.name.clone();
                 let spec = &crd.spec;
-                let name = crd.metadata.name.unwrap_or_default();
-                let group = spec.group.clone();
-                let kind = spec.names.kind.clone();
-                let plural = spec.names.plural.clone();
                 let scope = match spec.scope.as_str() {
                     "Namespaced" => "Namespaced",
                     _ => "Cluster",
                 }.to_string();
 
-                // Use the first served version
-                let version = spec.versions.iter()
-                    .find(|v| v.served)
-                    .map(|v| v.name.clone())
-                    .unwrap_or_else(|| "v1".to_string());
+                let versions = spec
+                    .versions
+                    .iter()
+                    .map(|v| CRDVersionInfo {
+                        name: v.name.clone(),
+                        served: v.served,
+                        storage: v.storage,
+                        deprecated: v.deprecated.unwrap_or(false),
+                        printer_columns: printer_columns(v),
+                    })
+                    .collect();
 
                 Some(CRDInfo {
-                    name,
-                    group,
+                    name: crd.metadata.name.clone().unwrap_or_default(),
+                    group: spec.group.clone(),
                     version,
-                    kind,
-                    plural,
+                    kind: spec.names.kind.clone(),
+                    plural: spec.names.plural.clone(),
                     scope,
+                    versions,
                 })
             })
             .collect();
@@ -48,25 +118,20 @@ impl CRDHandler {
         Ok(result)
     }
 
+    /// List instances of a CRD, computing its additionalPrinterColumns for each one.
+    /// `version` defaults to the storage version.
     pub async fn list_crd_instances(
         client: &Client,
         namespace: &str,
         group: &str,
-        version: &str,
+        version: Option<&str>,
         plural: &str,
         scope: &str,
     ) -> Result<Vec<CRDInstanceInfo>, DomainError> {
-        let ar = kube::api::ApiResource {
-            group: group.to_string(),
-            version: version.to_string(),
-            api_version: if group.is_empty() {
-                version.to_string()
-            } else {
-                format!("{}/{}", group, version)
-            },
-            kind: plural.to_string(),
-            plural: plural.to_string(),
-        };
+        let crd = get_crd(client, group, plural).await?;
+        let selected = select_version(&crd, version)?;
+        let columns = printer_columns(selected);
+        let ar = build_api_resource(group, &selected.name, &crd.spec.names.kind, plural);
 
         let api: Api<DynamicObject> = if scope == "Cluster" {
             Api::all_with(client.clone(), &ar)
@@ -79,13 +144,32 @@ impl CRDHandler {
             .items
             .into_iter()
             .map(|obj| {
+                let value = serde_json::to_value(&obj).unwrap_or(Value::Null);
                 let name = obj.metadata.name.unwrap_or_default();
                 let ns = obj.metadata.namespace.unwrap_or_default();
                 let age = format_age(obj.metadata.creation_timestamp.as_ref());
-                CRDInstanceInfo { name, namespace: ns, age }
+                let columns = columns.iter().map(|c| column_value(&value, c)).collect();
+                CRDInstanceInfo { name, namespace: ns, age, columns }
             })
             .collect();
 
         Ok(result)
     }
+
+    /// The openAPIV3Schema of a CRD version, for editor completion and validation.
+    pub async fn get_crd_schema(
+        client: &Client,
+        group: &str,
+        plural: &str,
+        version: Option<&str>,
+    ) -> Result<Value, DomainError> {
+        let crd = get_crd(client, group, plural).await?;
+        let selected = select_version(&crd, version)?;
+        let schema = selected
+            .schema
+            .as_ref()
+            .and_then(|s| s.open_api_v3_schema.as_ref())
+            .ok_or_else(|| DomainError::NotFound(format!("Version {} has no schema", selected.name)))?;
+        Ok(serde_json::to_value(schema)?)
+    }
 }
@@ -1,9 +1,59 @@
-use kube::Client;
+use kube::{discovery::ApiResource, Client};
 use serde_json::Value;
 
-use crate::domain::entities::GenericResourceDetailInfo;
+use crate::application::handlers::crd_handler::CRDHandler;
+use crate::application::services::schema_validator;
+use crate::application::services::yaml_positions::YamlPositions;
+use crate::domain::entities::{
+    ApplyResult, DryRunResult, FieldOwnershipInfo, GenericResourceDetailInfo, ManifestApplyResult, SchemaIssue,
+    SchemaValidationResult,
+};
 use crate::domain::errors::DomainError;
-use crate::infrastructure::kubernetes::{editing_repository, helpers as infra_helpers};
+use crate::infrastructure::kubernetes::client_manager::ClientManager;
+use crate::infrastructure::kubernetes::openapi_service::find_kind_schema;
+use crate::infrastructure::kubernetes::{editing_repository, finalizer_repository, helpers as infra_helpers};
+
+/// Split a YAML stream into documents, keeping the line offset of each one.
+fn split_documents(yaml_content: &str) -> Vec<(usize, String)> {
+    let mut docs = vec![(0, String::new())];
+    for (n, line) in yaml_content.lines().enumerate() {
+        if line.starts_with("---") {
+            docs.push((n + 1, String::new()));
+        } else if let Some((_, text)) = docs.last_mut() {
+            text.push_str(line);
+            text.push('\n');
+        }
+    }
+    docs
+}
+
+/// Schema violations for one document, checked against the server's OpenAPI v3 document
+/// and, for CRDs not (yet) published there, the CRD's own openAPIV3Schema.
+/// Returns `None` when no schema is available for the kind.
+async fn validate_document(
+    manager: &ClientManager,
+    client: &Client,
+    context: &str,
+    doc: &Value,
+) -> Option<Vec<schema_validator::Violation>> {
+    let api_version = doc["apiVersion"].as_str()?;
+    let kind = doc["kind"].as_str()?;
+
+    if let Ok(openapi) = manager.openapi.group_version_doc(client, context, api_version).await {
+        if let Some(schema) = find_kind_schema(&openapi, api_version, kind) {
+            return Some(schema_validator::validate(doc, schema, &openapi["components"]["schemas"]));
+        }
+    }
+
+    let (group, version) = api_version.split_once('/')?;
+    let resource = manager
+        .discovery
+        .resolve(client, context, &format!("{}.{}", kind, group))
+        .await
+        .ok()?;
+    let schema = CRDHandler::get_crd_schema(client, group, &resource.plural, Some(version)).await.ok()?;
+    Some(schema_validator::validate(doc, &schema, &Value::Null))
+}
 
 pub struct EditingHandler;
 
@@ -12,66 +62,173 @@ impl EditingHandler {
         client: &Client,
         ns: &str,
         name: &str,
-        group: &str,
-        version: &str,
-        kind: &str,
-        plural: &str,
+        ar: &ApiResource,
         cluster_scoped: bool,
     ) -> Result<String, DomainError> {
-        editing_repository::get_resource_yaml(client, ns, name, group, version, kind, plural, cluster_scoped).await
+        editing_repository::get_resource_yaml(client, ns, name, ar, cluster_scoped).await
     }
 
     pub async fn update_resource_yaml(
         client: &Client,
         ns: &str,
         name: &str,
-        group: &str,
-        version: &str,
-        kind: &str,
-        plural: &str,
+        ar: &ApiResource,
         yaml_content: &str,
         cluster_scoped: bool,
     ) -> Result<(), DomainError> {
-        editing_repository::update_resource_yaml(client, ns, name, group, version, kind, plural, yaml_content, cluster_scoped).await
+        editing_repository::update_resource_yaml(client, ns, name, ar, yaml_content, cluster_scoped).await
+    }
+
+    pub async fn dry_run_resource_yaml(
+        client: &Client,
+        ns: &str,
+        name: &str,
+        ar: &ApiResource,
+        yaml_content: &str,
+        cluster_scoped: bool,
+    ) -> Result<DryRunResult, DomainError> {
+        editing_repository::dry_run_resource_yaml(client, ns, name, ar, yaml_content, cluster_scoped).await
+    }
+
+    pub async fn apply_resource_yaml(
+        client: &Client,
+        ns: &str,
+        name: &str,
+        ar: &ApiResource,
+        yaml_content: &str,
+        force: bool,
+        cluster_scoped: bool,
+    ) -> Result<ApplyResult, DomainError> {
+        editing_repository::apply_resource_yaml(client, ns, name, ar, yaml_content, force, cluster_scoped).await
+    }
+
+    pub async fn apply_manifests(
+        manager: &ClientManager,
+        yaml_content: &str,
+        force: bool,
+    ) -> Result<Vec<ManifestApplyResult>, DomainError> {
+        let (client, ns, context) = manager.get_active_client_and_context().await?;
+        editing_repository::apply_manifests(&client, &manager.discovery, &context, &ns, yaml_content, force).await
     }
 
     pub async fn patch_resource(
         client: &Client,
         ns: &str,
         name: &str,
-        group: &str,
-        version: &str,
-        kind: &str,
-        plural: &str,
+        ar: &ApiResource,
         patch_json: &Value,
         cluster_scoped: bool,
     ) -> Result<(), DomainError> {
-        editing_repository::patch_resource(client, ns, name, group, version, kind, plural, patch_json, cluster_scoped).await
+        editing_repository::patch_resource(client, ns, name, ar, patch_json, cluster_scoped).await
+    }
+
+    pub async fn get_field_ownership(
+        client: &Client,
+        ns: &str,
+        name: &str,
+        ar: &ApiResource,
+        cluster_scoped: bool,
+    ) -> Result<FieldOwnershipInfo, DomainError> {
+        editing_repository::get_field_ownership(client, ns, name, ar, cluster_scoped).await
+    }
+
+    pub async fn remove_finalizer(
+        client: &Client,
+        ns: &str,
+        name: &str,
+        ar: &ApiResource,
+        cluster_scoped: bool,
+        finalizer: &str,
+    ) -> Result<Vec<String>, DomainError> {
+        finalizer_repository::remove_finalizer(client, ns, name, ar, cluster_scoped, finalizer).await
+    }
+
+    pub async fn dry_run_patch_resource(
+        client: &Client,
+        ns: &str,
+        name: &str,
+        ar: &ApiResource,
+        patch_json: &Value,
+        cluster_scoped: bool,
+    ) -> Result<DryRunResult, DomainError> {
+        editing_repository::dry_run_patch_resource(client, ns, name, ar, patch_json, cluster_scoped).await
     }
 
     pub async fn delete_resource(
         client: &Client,
         ns: &str,
         name: &str,
-        group: &str,
-        version: &str,
-        kind: &str,
-        plural: &str,
+        ar: &ApiResource,
         cluster_scoped: bool,
     ) -> Result<(), DomainError> {
-        editing_repository::delete_resource(client, ns, name, group, version, kind, plural, cluster_scoped).await
+        editing_repository::delete_resource(client, ns, name, ar, cluster_scoped).await
     }
 
     pub async fn get_generic_resource_detail(
         client: &Client,
         namespace: &str,
         name: &str,
-        group: &str,
-        version: &str,
-        kind: &str,
-        plural: &str,
+        ar: &ApiResource,
         cluster_scoped: bool,
     ) -> Result<GenericResourceDetailInfo, DomainError> {
-        infra_helpers::get_generic_resource_detail(client, namespace, name, group, version, kind, plural, cluster_scoped).await
+        infra_helpers::get_generic_resource_detail(client, namespace, name, ar, cluster_scoped).await
+    }
+
+    /// Validate every document in `yaml_content` against the cluster's schemas, reporting
+    /// unknown fields, type mismatches and missing required fields with line/column.
+    pub async fn validate_resource_yaml(
+        manager: &ClientManager,
+        yaml_content: &str,
+    ) -> Result<SchemaValidationResult, DomainError> {
+        let (client, context) = manager.get_client_and_context().await?;
+        let mut issues = Vec::new();
+        let mut unchecked = Vec::new();
+
+        for (document, (offset, text)) in split_documents(yaml_content).into_iter().enumerate() {
+            let doc: Value = match serde_yaml::from_str(&text) {
+                Ok(doc) => doc,
+                Err(e) => {
+                    let (line, column) = e.location().map(|l| (offset + l.line(), l.column())).unwrap_or((offset + 1, 1));
+                    issues.push(SchemaIssue {
+                        document,
+                        kind: String::new(),
+                        name: String::new(),
+                        path: String::new(),
+                        line,
+                        column,
+                        issue_type: "syntax".to_string(),
+                        message: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+            if doc.is_null() {
+                continue;
+            }
+
+            let kind = doc["kind"].as_str().unwrap_or_default().to_string();
+            let name = doc["metadata"]["name"].as_str().unwrap_or_default().to_string();
+            let Some(violations) = validate_document(manager, &client, &context, &doc).await else {
+                unchecked.push(format!("{} {}/{}", doc["apiVersion"].as_str().unwrap_or("?"), kind, name));
+                continue;
+            };
+
+            let positions = YamlPositions::index(&text, offset);
+            issues.extend(violations.into_iter().map(|v| {
+                let (line, column) = positions.locate(&v.path, offset);
+                SchemaIssue {
+                    document,
+                    kind: kind.clone(),
+                    name: name.clone(),
+                    path: v.path,
+                    line,
+                    column,
+                    issue_type: v.issue_type.to_string(),
+                    message: v.message,
+                }
+            }));
+        }
+
+        Ok(SchemaValidationResult { valid: issues.is_empty(), issues, unchecked })
     }
 }
@@ -1,6 +1,6 @@
 use kube::Client;
 
-use crate::domain::entities::{GRPCRouteDetailInfo, GatewayDetailInfo, HTTPRouteDetailInfo, IngressDetailInfo};
+use crate::domain::entities::{GRPCRouteDetailInfo, GatewayDetailInfo, HTTPRouteDetailInfo, IngressDetailInfo, ServiceDetailInfo};
 use crate::domain::errors::DomainError;
 use crate::infrastructure::kubernetes::{gateway_repository, networking_repository};
 
@@ -38,4 +38,12 @@ impl GatewayHandler {
     ) -> Result<IngressDetailInfo, DomainError> {
         networking_repository::get_ingress_detail(client, namespace, name).await
     }
+
+    pub async fn get_service_detail(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<ServiceDetailInfo, DomainError> {
+        networking_repository::get_service_detail(client, namespace, name).await
+    }
 }
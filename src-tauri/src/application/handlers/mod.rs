@@ -1,3 +1,5 @@
+pub mod autoscaling_handler;
+pub mod bulk_handler;
 pub mod cluster_handler;
 pub mod crd_handler;
 pub mod editing_handler;
@@ -5,6 +7,10 @@ pub mod gateway_handler;
 pub mod helm_handler;
 pub mod incident_handler;
 pub mod minikube_handler;
+pub mod networkpolicy_handler;
 pub mod newrelic_handler;
 pub mod pod_handler;
+pub mod policy_handler;
 pub mod resource_handler;
+pub mod template_handler;
+pub mod workload_handler;
@@ -0,0 +1,38 @@
+use kube::Client;
+
+use crate::domain::entities::{NetworkPolicyDetailInfo, ReachabilityResult};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::networkpolicy_repository;
+
+pub struct NetworkPolicyHandler;
+
+impl NetworkPolicyHandler {
+    pub async fn get_network_policy_detail(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<NetworkPolicyDetailInfo, DomainError> {
+        networkpolicy_repository::get_network_policy_detail(client, namespace, name).await
+    }
+
+    pub async fn analyze_reachability(
+        client: &Client,
+        source_namespace: &str,
+        source_pod: &str,
+        dest_namespace: &str,
+        dest_pod: &str,
+        port: i32,
+        protocol: &str,
+    ) -> Result<ReachabilityResult, DomainError> {
+        networkpolicy_repository::analyze_reachability(
+            client,
+            source_namespace,
+            source_pod,
+            dest_namespace,
+            dest_pod,
+            port,
+            protocol,
+        )
+        .await
+    }
+}
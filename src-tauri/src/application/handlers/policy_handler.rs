@@ -0,0 +1,42 @@
+use kube::Client;
+
+use crate::domain::entities::{LimitRangeDetailInfo, PdbDetailInfo, ResourceQuotaDetailInfo};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::{policy_repository, workload_repository};
+
+pub struct PolicyHandler;
+
+impl PolicyHandler {
+    pub async fn get_pdb_detail(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<PdbDetailInfo, DomainError> {
+        policy_repository::get_pdb_detail(client, namespace, name).await
+    }
+
+    pub async fn get_resource_quota_detail(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<ResourceQuotaDetailInfo, DomainError> {
+        policy_repository::get_resource_quota_detail(client, namespace, name).await
+    }
+
+    pub async fn get_limit_range_detail(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<LimitRangeDetailInfo, DomainError> {
+        policy_repository::get_limit_range_detail(client, namespace, name).await
+    }
+
+    pub async fn get_workload_blockers(
+        client: &Client,
+        namespace: &str,
+        kind: &str,
+        name: &str,
+    ) -> Result<Vec<String>, DomainError> {
+        workload_repository::get_workload_blockers(client, namespace, kind, name).await
+    }
+}
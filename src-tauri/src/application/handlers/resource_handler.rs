@@ -1,12 +1,15 @@
 use std::collections::HashMap;
 use kube::api::{Api, DynamicObject, ListParams};
 use kube::Client;
-use k8s_openapi::api::core::v1::{Endpoints, Pod, Service};
-use k8s_openapi::api::networking::v1::Ingress;
+use k8s_openapi::api::core::v1::{Pod, Service};
+use k8s_openapi::api::discovery::v1::EndpointSlice;
+use k8s_openapi::api::networking::v1::{Ingress, NetworkPolicy};
 use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet, StatefulSet, DaemonSet};
 use k8s_openapi::api::batch::v1::{Job, CronJob};
 
 use crate::application::services::formatting::format_age;
+use crate::application::services::labels::label_selector_matches;
+use crate::application::services::network_policy_analyzer;
 use crate::domain::entities::*;
 use crate::domain::errors::DomainError;
 use crate::infrastructure::kubernetes::*;
@@ -47,6 +50,22 @@ impl ResourceHandler {
         workload_repository::list_cronjobs(client, namespace).await
     }
 
+    pub async fn list_hpas(client: &Client, namespace: &str) -> Result<Vec<HpaInfo>, DomainError> {
+        autoscaling_repository::list_hpas(client, namespace).await
+    }
+
+    pub async fn list_pdbs(client: &Client, namespace: &str) -> Result<Vec<PdbInfo>, DomainError> {
+        policy_repository::list_pdbs(client, namespace).await
+    }
+
+    pub async fn list_resource_quotas(client: &Client, namespace: &str) -> Result<Vec<ResourceQuotaInfo>, DomainError> {
+        policy_repository::list_resource_quotas(client, namespace).await
+    }
+
+    pub async fn list_limit_ranges(client: &Client, namespace: &str) -> Result<Vec<LimitRangeInfo>, DomainError> {
+        policy_repository::list_limit_ranges(client, namespace).await
+    }
+
     pub async fn list_services(client: &Client, namespace: &str) -> Result<Vec<ServiceInfo>, DomainError> {
         networking_repository::list_services(client, namespace).await
     }
@@ -63,6 +82,10 @@ impl ResourceHandler {
         networking_repository::list_ingresses(client, namespace).await
     }
 
+    pub async fn list_network_policies(client: &Client, namespace: &str) -> Result<Vec<NetworkPolicyInfo>, DomainError> {
+        networkpolicy_repository::list_network_policies(client, namespace).await
+    }
+
     pub async fn list_gateways(client: &Client, namespace: &str) -> Result<Vec<GatewayInfo>, DomainError> {
         gateway_repository::list_gateways(client, namespace).await
     }
@@ -110,20 +133,50 @@ impl ResourceHandler {
         Ok(result)
     }
 
+    pub async fn list_resource_table(
+        client: &Client,
+        namespace: Option<&str>,
+        resource: &ApiResourceInfo,
+        label_selector: Option<&str>,
+    ) -> Result<ResourceTableInfo, DomainError> {
+        if !resource.verbs.iter().any(|v| v == "list") {
+            return Err(DomainError::Configuration(format!("{} does not support listing", resource.kind)));
+        }
+        let ar = build_api_resource(&resource.group, &resource.version, &resource.kind, &resource.plural);
+        table_repository::list_resource_table(client, namespace, &ar, !resource.namespaced, label_selector).await
+    }
+
+    /// Resources stuck in deletion for at least `min_seconds`, among the given types that
+    /// support listing.
+    pub async fn find_stuck_resources(
+        client: &Client,
+        namespace: Option<&str>,
+        resources: &[ApiResourceInfo],
+        min_seconds: i64,
+    ) -> Vec<StuckResourceInfo> {
+        let listable: Vec<ApiResourceInfo> = resources
+            .iter()
+            .filter(|r| r.verbs.iter().any(|v| v == "list"))
+            .cloned()
+            .collect();
+        finalizer_repository::find_stuck_resources(client, namespace, &listable, min_seconds).await
+    }
+
     pub async fn get_network_graph(client: &Client, namespace: &str) -> Result<NetworkGraphData, DomainError> {
         let svc_api: Api<Service> = Api::namespaced(client.clone(), namespace);
         let ing_api: Api<Ingress> = Api::namespaced(client.clone(), namespace);
         let pod_api: Api<Pod> = Api::namespaced(client.clone(), namespace);
-        let ep_api: Api<Endpoints> = Api::namespaced(client.clone(), namespace);
         let deploy_api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
         let rs_api: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+        let slice_api: Api<EndpointSlice> = Api::namespaced(client.clone(), namespace);
 
         let lp = ListParams::default();
-        let (services, ingresses, pods, endpoints, deploys, rsets) = tokio::try_join!(
+        let slice_lp = ListParams::default().labels("kubernetes.io/service-name");
+        let (services, ingresses, pods, endpoint_slices, deploys, rsets) = tokio::try_join!(
             svc_api.list(&lp),
             ing_api.list(&lp),
             pod_api.list(&lp),
-            ep_api.list(&lp),
+            slice_api.list(&slice_lp),
             deploy_api.list(&lp),
             rs_api.list(&lp),
         )?;
@@ -131,27 +184,8 @@ impl ResourceHandler {
         let mut nodes = Vec::new();
         let mut edges = Vec::new();
 
-        // Build endpoint details map: svc_name -> (count, pod_names)
-        let mut ep_details: HashMap<String, (i32, Vec<String>)> = HashMap::new();
-        for ep in &endpoints.items {
-            let name = ep.metadata.name.clone().unwrap_or_default();
-            let mut count = 0i32;
-            let mut pod_names = Vec::new();
-            if let Some(subs) = &ep.subsets {
-                for subset in subs {
-                    if let Some(addrs) = &subset.addresses {
-                        count += addrs.len() as i32;
-                        for addr in addrs {
-                            let pod_name = addr.target_ref.as_ref()
-                                .and_then(|tr| tr.name.clone())
-                                .unwrap_or_else(|| addr.ip.clone());
-                            pod_names.push(pod_name);
-                        }
-                    }
-                }
-            }
-            ep_details.insert(name, (count, pod_names));
-        }
+        // Resolve service backends from EndpointSlices: svc_name -> counts, zones, ready pods
+        let svc_backends = networking_repository::summarize_endpoint_slices(&endpoint_slices.items);
 
         // Build RS UID -> Deployment name map
         let mut rs_uid_to_deploy: HashMap<String, String> = HashMap::new();
@@ -184,14 +218,14 @@ impl ResourceHandler {
             }
         }
 
-        // Build deployment info map: name -> "ready/desired|podCount"
-        let mut deploy_info: HashMap<String, String> = HashMap::new();
+        // Build deployment info map: name -> (ready/desired, podCount)
+        let mut deploy_info: HashMap<String, (String, i32)> = HashMap::new();
         for deploy in &deploys.items {
             let name = deploy.metadata.name.clone().unwrap_or_default();
             let ready = deploy.status.as_ref().and_then(|s| s.ready_replicas).unwrap_or(0);
             let desired = deploy.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
             let pod_count = pod_to_deploy.values().filter(|d| **d == name).count() as i32;
-            deploy_info.insert(name, format!("{}/{}|{}", ready, desired, pod_count));
+            deploy_info.insert(name, (format!("{}/{}", ready, desired), pod_count));
         }
 
         // Build service selector map and resolve service -> deployments
@@ -236,27 +270,31 @@ impl ResourceHandler {
             svc_to_deploys.insert(svc_name.clone(), deploy_set);
         }
 
-        // Add Service nodes with embedded endpoint + deployment data in status
-        // Format: "svcType|epCount|epPod1,epPod2,...|deployName:ready/desired:podCount;deployName2:..."
+        // Add Service nodes with their endpoints and backing workloads
         for svc in &services.items {
             let name = svc.metadata.name.clone().unwrap_or_default();
             let svc_type = svc.spec.as_ref()
-                .and_then(|s| s.type_.as_deref())
-                .unwrap_or("ClusterIP");
-            let (ep_count, ep_pods) = ep_details.get(&name).cloned().unwrap_or((0, Vec::new()));
-            let deploys_str: String = svc_to_deploys.get(&name)
+                .and_then(|s| s.type_.clone())
+                .unwrap_or_else(|| "ClusterIP".to_string());
+            let backends = svc_backends.get(&name).cloned().unwrap_or_default();
+            let workloads = svc_to_deploys.get(&name)
                 .map(|ds| ds.iter().map(|d| {
-                    let info = deploy_info.get(d).cloned().unwrap_or_else(|| "0/0|0".to_string());
-                    format!("{}:{}", d, info)
-                }).collect::<Vec<_>>().join(";"))
+                    let (ready, pod_count) = deploy_info.get(d).cloned().unwrap_or_else(|| ("0/0".to_string(), 0));
+                    ServiceNodeWorkload { name: d.clone(), ready, pod_count }
+                }).collect())
                 .unwrap_or_default();
-            let ep_pods_str = ep_pods.join(",");
 
             nodes.push(GraphNode {
                 id: format!("svc:{}", name),
                 label: name.clone(),
                 node_type: "Service".to_string(),
-                status: format!("{}|{}|{}|{}", svc_type, ep_count, ep_pods_str, deploys_str),
+                status: svc_type.clone(),
+                service: Some(ServiceNodeInfo {
+                    service_type: svc_type,
+                    endpoint_pods: backends.ready_pods,
+                    endpoints: backends.health,
+                    workloads,
+                }),
             });
         }
 
@@ -273,6 +311,7 @@ impl ResourceHandler {
                 label: name.clone(),
                 node_type: "Ingress".to_string(),
                 status: hosts.join(", "),
+                service: None,
             });
             // Collect unique service targets
             let mut seen_targets: HashMap<String, String> = HashMap::new();
@@ -313,6 +352,7 @@ impl ResourceHandler {
                         label: gw.name.clone(),
                         node_type: "Gateway".to_string(),
                         status: gw.gateway_class.clone(),
+                        service: None,
                     });
                 }
                 // Resolve HTTPRoutes: Gateway -> Service (deduplicated)
@@ -365,6 +405,71 @@ impl ResourceHandler {
             Err(_) => {} // Gateway API not available, skip
         }
 
+        // NetworkPolicy overlay: policy -> services whose pods it selects, and
+        // service -> policy where the service's pods are allowed in by an ingress rule
+        let np_api: Api<NetworkPolicy> = Api::namespaced(client.clone(), namespace);
+        if let Ok(policies) = np_api.list(&lp).await {
+            let svc_pods: HashMap<String, Vec<&Pod>> = svc_selectors.iter()
+                .map(|(svc_name, sel)| {
+                    let backing = pods.items.iter()
+                        .filter(|p| {
+                            let labels = p.metadata.labels.as_ref();
+                            sel.iter().all(|(k, v)| labels.and_then(|l| l.get(k)) == Some(v))
+                        })
+                        .collect();
+                    (svc_name.clone(), backing)
+                })
+                .collect();
+            let pod_labels = |p: &Pod| p.metadata.labels.clone().unwrap_or_default();
+
+            for np in &policies.items {
+                let name = np.metadata.name.clone().unwrap_or_default();
+                let node_id = format!("netpol:{}", name);
+                let policy_types = network_policy_analyzer::effective_policy_types(np);
+                let spec = match &np.spec {
+                    Some(s) => s,
+                    None => continue,
+                };
+                nodes.push(GraphNode {
+                    id: node_id.clone(),
+                    label: name.clone(),
+                    node_type: "NetworkPolicy".to_string(),
+                    status: policy_types.join(","),
+                    service: None,
+                });
+
+                for (svc_name, backing) in &svc_pods {
+                    if backing.iter().any(|p| label_selector_matches(&spec.pod_selector, &pod_labels(p))) {
+                        edges.push(GraphEdge {
+                            source: node_id.clone(),
+                            target: format!("svc:{}", svc_name),
+                            label: "applies".to_string(),
+                        });
+                    }
+                }
+
+                let peer_selectors: Vec<_> = spec.ingress.as_deref().unwrap_or_default().iter()
+                    .flat_map(|r| r.from.as_deref().unwrap_or_default())
+                    .filter(|peer| peer.ip_block.is_none() && peer.namespace_selector.is_none())
+                    .filter_map(|peer| peer.pod_selector.as_ref())
+                    .collect();
+                for (svc_name, backing) in &svc_pods {
+                    let allowed = backing.iter().any(|p| {
+                        let labels = pod_labels(p);
+                        !label_selector_matches(&spec.pod_selector, &labels)
+                            && peer_selectors.iter().any(|sel| label_selector_matches(sel, &labels))
+                    });
+                    if allowed {
+                        edges.push(GraphEdge {
+                            source: format!("svc:{}", svc_name),
+                            target: node_id.clone(),
+                            label: "allowed from".to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
         Ok(NetworkGraphData { nodes, edges })
     }
 
@@ -407,6 +512,7 @@ impl ResourceHandler {
                         label: name,
                         node_type: $kind.to_string(),
                         status,
+                        service: None,
                     });
                     if !uid.is_empty() {
                         uid_to_id.insert(uid, node_id);
@@ -436,6 +542,7 @@ impl ResourceHandler {
                 label: name,
                 node_type: "Pod".to_string(),
                 status: phase,
+                service: None,
             });
             if !uid.is_empty() {
                 uid_to_id.insert(uid, node_id);
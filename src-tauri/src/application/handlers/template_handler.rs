@@ -0,0 +1,101 @@
+use std::collections::BTreeMap;
+
+use crate::application::services::config_db::ConfigDB;
+use crate::application::services::templates;
+use crate::domain::entities::{ManifestApplyResult, ResourceTemplate};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::client_manager::ClientManager;
+use crate::infrastructure::kubernetes::editing_repository;
+
+pub struct TemplateHandler;
+
+impl TemplateHandler {
+    pub fn list_templates(config_db: &ConfigDB) -> Result<Vec<ResourceTemplate>, DomainError> {
+        let mut all = templates::builtin_templates();
+        all.extend(templates::load_user_templates(config_db)?);
+        Ok(all)
+    }
+
+    pub fn get_template(config_db: &ConfigDB, id: &str) -> Result<ResourceTemplate, DomainError> {
+        Self::list_templates(config_db)?
+            .into_iter()
+            .find(|t| t.id == id)
+            .ok_or_else(|| DomainError::NotFound(format!("Template {} not found", id)))
+    }
+
+    /// Insert or replace a user template. Templates without an id get one derived from
+    /// their name; built-in templates can't be overwritten.
+    pub fn save_template(config_db: &ConfigDB, mut template: ResourceTemplate) -> Result<ResourceTemplate, DomainError> {
+        if template.name.trim().is_empty() {
+            return Err(DomainError::Configuration("Template name is required".to_string()));
+        }
+        serde_yaml::from_str::<serde_yaml::Value>(&template.body)
+            .map_err(|e| DomainError::Configuration(format!("Template body is not valid YAML: {}", e)))?;
+        if template.id.is_empty() {
+            let slug: String = template
+                .name
+                .to_lowercase()
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+                .collect();
+            template.id = format!("user-{}", slug.trim_matches('-'));
+        }
+        if templates::builtin_templates().iter().any(|t| t.id == template.id) {
+            return Err(DomainError::Configuration(format!("Template {} is built in and can't be modified", template.id)));
+        }
+        template.builtin = false;
+
+        let mut user = templates::load_user_templates(config_db)?;
+        match user.iter_mut().find(|t| t.id == template.id) {
+            Some(existing) => *existing = template.clone(),
+            None => user.push(template.clone()),
+        }
+        templates::store_user_templates(config_db, &user)?;
+        Ok(template)
+    }
+
+    pub fn delete_template(config_db: &ConfigDB, id: &str) -> Result<(), DomainError> {
+        if templates::builtin_templates().iter().any(|t| t.id == id) {
+            return Err(DomainError::Configuration(format!("Template {} is built in and can't be deleted", id)));
+        }
+        let mut user = templates::load_user_templates(config_db)?;
+        let before = user.len();
+        user.retain(|t| t.id != id);
+        if user.len() == before {
+            return Err(DomainError::NotFound(format!("Template {} not found", id)));
+        }
+        templates::store_user_templates(config_db, &user)
+    }
+
+    pub fn render_template(
+        config_db: &ConfigDB,
+        id: &str,
+        values: &BTreeMap<String, String>,
+    ) -> Result<String, DomainError> {
+        let template = Self::get_template(config_db, id)?;
+        templates::render(&template, values)
+    }
+
+    /// Render a template and create its objects. Everything is first submitted as a
+    /// server-side dry run; the real create only happens when every object passes and
+    /// `dry_run` is false, so a bad value never leaves a half-created set behind.
+    pub async fn create_from_template(
+        manager: &ClientManager,
+        config_db: &ConfigDB,
+        id: &str,
+        values: &BTreeMap<String, String>,
+        dry_run: bool,
+    ) -> Result<Vec<ManifestApplyResult>, DomainError> {
+        let (client, ns, context) = manager.get_active_client_and_context().await?;
+        let mut values = values.clone();
+        values.entry("namespace".to_string()).or_insert_with(|| ns.clone());
+        let yaml = Self::render_template(config_db, id, &values)?;
+
+        let discovery = &manager.discovery;
+        let checked = editing_repository::create_manifests(&client, discovery, &context, &ns, &yaml, true).await?;
+        if dry_run || checked.iter().any(|r| !r.success) {
+            return Ok(checked);
+        }
+        editing_repository::create_manifests(&client, discovery, &context, &ns, &yaml, false).await
+    }
+}
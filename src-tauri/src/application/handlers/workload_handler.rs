@@ -0,0 +1,157 @@
+use kube::Client;
+
+use crate::domain::entities::{
+    CronJobRunInfo, CronScheduleInfo, DeploymentDetailInfo, JobDetailInfo, RolloutUndoPreview, WorkloadEdit,
+    WorkloadEditPreview,
+};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::{batch_repository, deployment_repository, workload_repository};
+
+pub struct WorkloadHandler;
+
+impl WorkloadHandler {
+    pub async fn get_deployment_detail(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<DeploymentDetailInfo, DomainError> {
+        deployment_repository::get_deployment_detail(client, namespace, name).await
+    }
+
+    pub async fn preview_rollout_undo(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+        revision: Option<i64>,
+    ) -> Result<RolloutUndoPreview, DomainError> {
+        deployment_repository::preview_rollout_undo(client, namespace, name, revision).await
+    }
+
+    pub async fn rollout_undo(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+        revision: Option<i64>,
+    ) -> Result<RolloutUndoPreview, DomainError> {
+        deployment_repository::rollout_undo(client, namespace, name, revision).await
+    }
+
+    pub async fn set_deployment_paused(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+        paused: bool,
+    ) -> Result<(), DomainError> {
+        deployment_repository::set_deployment_paused(client, namespace, name, paused).await
+    }
+
+    pub async fn scale(
+        client: &Client,
+        namespace: &str,
+        kind: &str,
+        name: &str,
+        replicas: i32,
+    ) -> Result<(), DomainError> {
+        workload_repository::scale_workload(client, namespace, kind, name, replicas).await
+    }
+
+    pub async fn restart(client: &Client, namespace: &str, kind: &str, name: &str) -> Result<(), DomainError> {
+        workload_repository::restart_workload(client, namespace, kind, name).await
+    }
+
+    pub async fn update_resources(
+        client: &Client,
+        namespace: &str,
+        kind: &str,
+        name: &str,
+        container_name: &str,
+        requests_cpu: &str,
+        requests_memory: &str,
+        limits_cpu: &str,
+        limits_memory: &str,
+    ) -> Result<(), DomainError> {
+        workload_repository::update_workload_resources(
+            client,
+            namespace,
+            kind,
+            name,
+            container_name,
+            requests_cpu,
+            requests_memory,
+            limits_cpu,
+            limits_memory,
+        )
+        .await
+    }
+
+    pub async fn set_statefulset_partition(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+        partition: i32,
+    ) -> Result<(), DomainError> {
+        workload_repository::set_statefulset_partition(client, namespace, name, partition).await
+    }
+
+    pub async fn preview_workload_edit(
+        client: &Client,
+        namespace: &str,
+        kind: &str,
+        name: &str,
+        edit: &WorkloadEdit,
+    ) -> Result<WorkloadEditPreview, DomainError> {
+        workload_repository::preview_workload_edit(client, namespace, kind, name, edit).await
+    }
+
+    pub async fn apply_workload_edit(
+        client: &Client,
+        namespace: &str,
+        kind: &str,
+        name: &str,
+        edit: &WorkloadEdit,
+    ) -> Result<WorkloadEditPreview, DomainError> {
+        workload_repository::apply_workload_edit(client, namespace, kind, name, edit).await
+    }
+
+    pub async fn trigger_cronjob(client: &Client, namespace: &str, name: &str) -> Result<String, DomainError> {
+        batch_repository::trigger_cronjob(client, namespace, name).await
+    }
+
+    pub async fn set_cronjob_suspend(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+        suspend: bool,
+    ) -> Result<(), DomainError> {
+        batch_repository::set_cronjob_suspend(client, namespace, name, suspend).await
+    }
+
+    pub async fn list_cronjob_runs(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Vec<CronJobRunInfo>, DomainError> {
+        batch_repository::list_cronjob_runs(client, namespace, name).await
+    }
+
+    pub async fn get_cronjob_schedule(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+        count: usize,
+    ) -> Result<CronScheduleInfo, DomainError> {
+        batch_repository::get_cronjob_schedule(client, namespace, name, count).await
+    }
+
+    pub async fn rerun_job(client: &Client, namespace: &str, name: &str) -> Result<String, DomainError> {
+        batch_repository::rerun_job(client, namespace, name).await
+    }
+
+    pub async fn get_job_detail(
+        client: &Client,
+        namespace: &str,
+        name: &str,
+    ) -> Result<JobDetailInfo, DomainError> {
+        batch_repository::get_job_detail(client, namespace, name).await
+    }
+}
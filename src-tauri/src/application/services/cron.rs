@@ -0,0 +1,193 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+
+/// A parsed standard 5-field cron expression, as accepted by the CronJob controller.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    dom_star: bool,
+    dow_star: bool,
+}
+
+const MONTH_NAMES: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+const DAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+fn parse_value(value: &str, names: &[&str], offset: u32) -> Result<u32, String> {
+    if let Some(idx) = names.iter().position(|n| n.eq_ignore_ascii_case(value)) {
+        return Ok(idx as u32 + offset);
+    }
+    value.parse::<u32>().map_err(|_| format!("invalid value \"{}\"", value))
+}
+
+/// Parse one field into a bitmask. Returns the mask and whether the field was `*` or `?`.
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str], name_offset: u32) -> Result<(u64, bool), String> {
+    let mut mask = 0u64;
+    let star = field.starts_with('*') || field.starts_with('?');
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((r, s)) => {
+                let step = s.parse::<u32>().map_err(|_| format!("invalid step \"{}\"", s))?;
+                if step == 0 {
+                    return Err("step must be greater than 0".to_string());
+                }
+                (r, Some(step))
+            }
+            None => (part, None),
+        };
+
+        let (start, end) = if range == "*" || range == "?" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            (parse_value(a, names, name_offset)?, parse_value(b, names, name_offset)?)
+        } else {
+            let v = parse_value(range, names, name_offset)?;
+            // "5/15" means "from 5 to the end, every 15"
+            (v, if step.is_some() { max } else { v })
+        };
+
+        if start < min || end > max || start > end {
+            return Err(format!("\"{}\" is out of range {}-{}", part, min, max));
+        }
+        let mut v = start;
+        while v <= end {
+            mask |= 1 << v;
+            v += step.unwrap_or(1);
+        }
+    }
+    Ok((mask, star))
+}
+
+impl CronSchedule {
+    pub fn parse(expr: &str) -> Result<Self, String> {
+        let expr = match expr.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other => other,
+        };
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(format!("expected 5 fields, found {}", fields.len()));
+        }
+
+        let (minutes, _) = parse_field(fields[0], 0, 59, &[], 0)?;
+        let (hours, _) = parse_field(fields[1], 0, 23, &[], 0)?;
+        let (days_of_month, dom_star) = parse_field(fields[2], 1, 31, &[], 0)?;
+        let (months, _) = parse_field(fields[3], 1, 12, &MONTH_NAMES, 1)?;
+        let (mut days_of_week, dow_star) = parse_field(fields[4], 0, 7, &DAY_NAMES, 0)?;
+        // 7 is an alias for Sunday
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+
+        Ok(CronSchedule { minutes, hours, days_of_month, months, days_of_week, dom_star, dow_star })
+    }
+
+    fn day_matches(&self, date: NaiveDate) -> bool {
+        let dom = self.days_of_month & (1 << date.day()) != 0;
+        let dow = self.days_of_week & (1 << date.weekday().num_days_from_sunday()) != 0;
+        // Standard cron: if either day field is restricted, a match on either one is enough
+        if self.dom_star || self.dow_star {
+            dom && dow
+        } else {
+            dom || dow
+        }
+    }
+
+    /// First matching wall-clock time strictly after `after`, searching up to five years ahead.
+    pub fn next_local(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let mut t = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let limit = after + Duration::days(366 * 5);
+
+        while t <= limit {
+            if self.months & (1 << t.month()) == 0 {
+                let (y, m) = if t.month() == 12 { (t.year() + 1, 1) } else { (t.year(), t.month() + 1) };
+                t = NaiveDate::from_ymd_opt(y, m, 1)?.and_time(NaiveTime::MIN);
+                continue;
+            }
+            if !self.day_matches(t.date()) {
+                t = t.date().succ_opt()?.and_time(NaiveTime::MIN);
+                continue;
+            }
+            if self.hours & (1 << t.hour()) == 0 {
+                t = t.with_minute(0)? + Duration::hours(1);
+                continue;
+            }
+            if self.minutes & (1 << t.minute()) == 0 {
+                t += Duration::minutes(1);
+                continue;
+            }
+            return Some(t);
+        }
+        None
+    }
+
+    /// Next `count` run times after `after`, evaluated in the wall-clock time of `tz`.
+    /// Times that fall into a DST gap are skipped; ambiguous times run once, at the earlier instant.
+    pub fn next_runs<Tz: TimeZone>(&self, after: &chrono::DateTime<Tz>, count: usize) -> Vec<chrono::DateTime<Tz>> {
+        let tz = after.timezone();
+        let mut runs = Vec::with_capacity(count);
+        let mut cursor = after.naive_local();
+        while runs.len() < count {
+            cursor = match self.next_local(cursor) {
+                Some(t) => t,
+                None => break,
+            };
+            if let Some(dt) = tz.from_local_datetime(&cursor).earliest() {
+                runs.push(dt);
+            }
+        }
+        runs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn at(s: &str) -> chrono::DateTime<Utc> {
+        chrono::DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_expressions() {
+        assert!(CronSchedule::parse("* * * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("0 0 * FOO *").is_err());
+        assert!(CronSchedule::parse("@hourly").is_ok());
+    }
+
+    #[test]
+    fn test_next_runs_step_and_names() {
+        let s = CronSchedule::parse("*/15 9-10 * * MON-FRI").unwrap();
+        // 2024-06-07 is a Friday
+        let runs = s.next_runs(&at("2024-06-07T10:50:00Z"), 3);
+        let runs: Vec<String> = runs.iter().map(|r| r.to_rfc3339()).collect();
+        assert_eq!(
+            runs,
+            vec![
+                "2024-06-10T09:00:00+00:00",
+                "2024-06-10T09:15:00+00:00",
+                "2024-06-10T09:30:00+00:00",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_day_of_month_or_day_of_week() {
+        // Either the 1st of the month or any Sunday
+        let s = CronSchedule::parse("0 0 1 * 0").unwrap();
+        // 2024-06-01 is a Saturday, 2024-06-02 a Sunday
+        let runs = s.next_runs(&at("2024-05-31T12:00:00Z"), 3);
+        let days: Vec<u32> = runs.iter().map(|r| r.day()).collect();
+        assert_eq!(days, vec![1, 2, 9]);
+    }
+}
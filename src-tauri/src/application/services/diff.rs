@@ -0,0 +1,68 @@
+use crate::domain::entities::common::DiffLine;
+
+/// Line diff of `old` vs `new` based on the longest common subsequence.
+/// Every line is returned, tagged `"context"`, `"removed"` or `"added"`.
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    let (n, m) = (a.len(), b.len());
+
+    // lcs[i][j] = LCS length of a[i..] and b[j..]
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let line = |op: &str, text: &str| DiffLine { op: op.to_string(), text: text.to_string() };
+    let mut out = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            out.push(line("context", a[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            out.push(line("removed", a[i]));
+            i += 1;
+        } else {
+            out.push(line("added", b[j]));
+            j += 1;
+        }
+    }
+    out.extend(a[i..].iter().map(|l| line("removed", l)));
+    out.extend(b[j..].iter().map(|l| line("added", l)));
+    out
+}
+
+pub fn has_changes(diff: &[DiffLine]) -> bool {
+    diff.iter().any(|l| l.op != "context")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines() {
+        let diff = diff_lines("a\nb\nc\n", "a\nx\nc\nd\n");
+        let ops: Vec<(&str, &str)> = diff.iter().map(|l| (l.op.as_str(), l.text.as_str())).collect();
+        assert_eq!(
+            ops,
+            vec![
+                ("context", "a"),
+                ("removed", "b"),
+                ("added", "x"),
+                ("context", "c"),
+                ("added", "d"),
+            ]
+        );
+        assert!(has_changes(&diff));
+        assert!(!has_changes(&diff_lines("same\n", "same\n")));
+    }
+}
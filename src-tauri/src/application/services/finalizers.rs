@@ -0,0 +1,96 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry;
+
+use crate::domain::entities::FinalizerInfo;
+
+/// Finalizers set by Kubernetes itself, with the controller responsible and what it waits for.
+const KNOWN_FINALIZERS: [(&str, &str, &str); 9] = [
+    ("kubernetes", "namespace controller", "Waits until every object in the namespace is deleted"),
+    ("foregroundDeletion", "garbage collector", "Waits for dependents with blockOwnerDeletion to be deleted"),
+    ("orphan", "garbage collector", "Waits while ownerReferences are removed from dependents"),
+    ("kubernetes.io/pvc-protection", "kube-controller-manager (PVC protection)", "Waits until no pod uses the claim"),
+    ("kubernetes.io/pv-protection", "kube-controller-manager (PV protection)", "Waits until the volume is no longer bound to a claim"),
+    ("service.kubernetes.io/load-balancer-cleanup", "service controller (cloud provider)", "Waits for the cloud load balancer to be released"),
+    ("batch.kubernetes.io/job-tracking", "job controller", "Waits for the Job controller to account for the pod"),
+    ("customresourcecleanup.apiextensions.k8s.io", "apiextensions-apiserver", "Waits until all instances of the custom resource are deleted"),
+    ("kubernetes.io/legacy-token-invalidation", "kube-controller-manager", "Waits for the legacy service account token to be cleaned up"),
+];
+
+/// Field managers whose managed fields include `finalizer` in `metadata.finalizers`,
+/// i.e. the clients that added it.
+pub fn finalizer_setters(managed_fields: &[ManagedFieldsEntry], finalizer: &str) -> Vec<String> {
+    let key = format!("v:{}", serde_json::Value::String(finalizer.to_string()));
+    managed_fields
+        .iter()
+        .filter(|entry| {
+            entry
+                .fields_v1
+                .as_ref()
+                .is_some_and(|f| f.0["f:metadata"]["f:finalizers"].get(&key).is_some())
+        })
+        .filter_map(|entry| entry.manager.clone())
+        .collect()
+}
+
+/// Identify which controller is expected to clear `finalizer`: a built-in one, the field
+/// manager that added it, or failing that the controller owning its domain prefix.
+pub fn describe_finalizer(finalizer: &str, managed_fields: &[ManagedFieldsEntry]) -> FinalizerInfo {
+    if let Some((_, controller, hint)) = KNOWN_FINALIZERS.iter().find(|(name, _, _)| *name == finalizer) {
+        return FinalizerInfo {
+            name: finalizer.to_string(),
+            controller: controller.to_string(),
+            hint: hint.to_string(),
+        };
+    }
+
+    let domain = finalizer.split_once('/').map(|(d, _)| d).unwrap_or(finalizer);
+    let setters = finalizer_setters(managed_fields, finalizer);
+    let controller = if setters.is_empty() {
+        format!("controller for {}", domain)
+    } else {
+        setters.join(", ")
+    };
+    FinalizerInfo {
+        name: finalizer.to_string(),
+        controller,
+        hint: format!(
+            "Check that the controller for {} is running and can reach this object; removing the finalizer skips its cleanup",
+            domain
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::FieldsV1;
+    use serde_json::json;
+
+    fn entry(manager: &str, fields: serde_json::Value) -> ManagedFieldsEntry {
+        ManagedFieldsEntry {
+            manager: Some(manager.to_string()),
+            operation: Some("Update".to_string()),
+            fields_v1: Some(FieldsV1(fields)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_describe_finalizer() {
+        let managed = vec![
+            entry("kubectl-client-side-apply", json!({ "f:spec": { "f:replicas": {} } })),
+            entry(
+                "cert-manager-certificates",
+                json!({ "f:metadata": { "f:finalizers": { ".": {}, "v:\"cert-manager.io/cleanup\"": {} } } }),
+            ),
+        ];
+
+        let known = describe_finalizer("kubernetes.io/pvc-protection", &managed);
+        assert_eq!(known.controller, "kube-controller-manager (PVC protection)");
+
+        let set_by = describe_finalizer("cert-manager.io/cleanup", &managed);
+        assert_eq!(set_by.controller, "cert-manager-certificates");
+
+        let unknown = describe_finalizer("example.com/finalizer", &managed);
+        assert_eq!(unknown.controller, "controller for example.com");
+    }
+}
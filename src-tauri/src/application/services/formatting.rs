@@ -25,6 +25,38 @@ pub fn format_age(creation: Option<&Time>) -> String {
     }
 }
 
+/// Parse a K8s quantity string ("500m", "2", "1Gi", "10k") into a plain number
+/// so usage can be compared against limits. Returns `None` for malformed input.
+pub fn parse_quantity(q: &str) -> Option<f64> {
+    let q = q.trim();
+    let split = q
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(q.len());
+    let (num, suffix) = q.split_at(split);
+    let value: f64 = num.parse().ok()?;
+    let multiplier = match suffix {
+        "" => 1.0,
+        "n" => 1e-9,
+        "u" => 1e-6,
+        "m" => 1e-3,
+        "k" => 1e3,
+        "M" => 1e6,
+        "G" => 1e9,
+        "T" => 1e12,
+        "P" => 1e15,
+        "E" => 1e18,
+        "Ki" => 1024.0,
+        "Mi" => 1024.0_f64.powi(2),
+        "Gi" => 1024.0_f64.powi(3),
+        "Ti" => 1024.0_f64.powi(4),
+        "Pi" => 1024.0_f64.powi(5),
+        "Ei" => 1024.0_f64.powi(6),
+        s if s.starts_with('e') || s.starts_with('E') => 10f64.powi(s[1..].parse().ok()?),
+        _ => return None,
+    };
+    Some(value * multiplier)
+}
+
 /// Format a K8s Probe into a ProbeInfo entity.
 pub fn format_probe(probe: &k8s_openapi::api::core::v1::Probe) -> ProbeInfo {
     let (probe_type, details) = if let Some(http) = &probe.http_get {
@@ -103,4 +135,15 @@ mod tests {
     fn test_format_age_none() {
         assert_eq!(format_age(None), "Unknown");
     }
+
+    #[test]
+    fn test_parse_quantity() {
+        assert_eq!(parse_quantity("2"), Some(2.0));
+        assert_eq!(parse_quantity("500m"), Some(0.5));
+        assert_eq!(parse_quantity("1Gi"), Some(1024.0 * 1024.0 * 1024.0));
+        assert_eq!(parse_quantity("10k"), Some(10_000.0));
+        assert_eq!(parse_quantity("1e3"), Some(1000.0));
+        assert_eq!(parse_quantity("abc"), None);
+        assert_eq!(parse_quantity("5Xi"), None);
+    }
 }
@@ -0,0 +1,199 @@
+use serde_json::Value;
+
+/// The subset of kubectl JSONPath used by CRD `additionalPrinterColumns`:
+/// `.a.b`, `['a']`, `[0]`, `[-1]`, `[*]`, `.*` and `[?(@.x=="y")]` / `[?(@.x)]` filters.
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Field(String),
+    Index(i64),
+    Wildcard,
+    Filter { path: Vec<String>, condition: Option<(bool, Value)> },
+}
+
+fn parse_literal(raw: &str) -> Value {
+    let raw = raw.trim();
+    for quote in ['"', '\''] {
+        if raw.len() >= 2 && raw.starts_with(quote) && raw.ends_with(quote) {
+            return Value::String(raw[1..raw.len() - 1].to_string());
+        }
+    }
+    serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()))
+}
+
+fn parse_bracket(inner: &str) -> Result<Segment, String> {
+    let inner = inner.trim();
+    if inner == "*" {
+        return Ok(Segment::Wildcard);
+    }
+    if let Some(expr) = inner.strip_prefix('?') {
+        let expr = expr.trim().trim_start_matches('(').trim_end_matches(')').trim();
+        let (lhs, condition) = if let Some((l, r)) = expr.split_once("==") {
+            (l, Some((true, parse_literal(r))))
+        } else if let Some((l, r)) = expr.split_once("!=") {
+            (l, Some((false, parse_literal(r))))
+        } else {
+            (expr, None)
+        };
+        let lhs = lhs.trim();
+        let field_path = lhs
+            .strip_prefix("@.")
+            .ok_or_else(|| format!("unsupported filter \"{}\"", expr))?;
+        return Ok(Segment::Filter {
+            path: field_path.split('.').map(str::to_string).collect(),
+            condition,
+        });
+    }
+    match parse_literal(inner) {
+        Value::String(key) if inner.starts_with(['"', '\'']) => Ok(Segment::Field(key)),
+        Value::Number(n) if n.is_i64() => Ok(Segment::Index(n.as_i64().unwrap_or_default())),
+        _ => Err(format!("unsupported subscript \"[{}]\"", inner)),
+    }
+}
+
+fn parse(path: &str) -> Result<Vec<Segment>, String> {
+    let mut path = path.trim();
+    if let Some(inner) = path.strip_prefix('{').and_then(|p| p.strip_suffix('}')) {
+        path = inner.trim();
+    }
+    let path = path.strip_prefix('$').unwrap_or(path);
+    let chars: Vec<char> = path.chars().collect();
+
+    let read_ident = |start: usize| {
+        let mut end = start;
+        while end < chars.len() && chars[end] != '.' && chars[end] != '[' {
+            end += 1;
+        }
+        (chars[start..end].iter().collect::<String>(), end)
+    };
+
+    let mut segments = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '.' if chars.get(i + 1) == Some(&'*') => {
+                segments.push(Segment::Wildcard);
+                i += 2;
+            }
+            '.' => {
+                let (ident, end) = read_ident(i + 1);
+                if ident.is_empty() {
+                    return Err(format!("empty field name in \"{}\"", path));
+                }
+                segments.push(Segment::Field(ident));
+                i = end;
+            }
+            '[' => {
+                let mut end = i + 1;
+                let mut quote: Option<char> = None;
+                while end < chars.len() {
+                    match (quote, chars[end]) {
+                        (None, ']') => break,
+                        (None, q @ ('"' | '\'')) => quote = Some(q),
+                        (Some(q), c) if c == q => quote = None,
+                        _ => {}
+                    }
+                    end += 1;
+                }
+                if end >= chars.len() {
+                    return Err(format!("unterminated \"[\" in \"{}\"", path));
+                }
+                let inner: String = chars[i + 1..end].iter().collect();
+                segments.push(parse_bracket(&inner)?);
+                i = end + 1;
+            }
+            _ => {
+                let (ident, end) = read_ident(i);
+                segments.push(Segment::Field(ident));
+                i = end;
+            }
+        }
+    }
+    Ok(segments)
+}
+
+fn lookup<'a>(value: &'a Value, path: &[String]) -> Option<&'a Value> {
+    path.iter().try_fold(value, |v, key| v.get(key))
+}
+
+/// Evaluate `path` against `root`, returning every matched value in document order.
+pub fn evaluate<'a>(root: &'a Value, path: &str) -> Result<Vec<&'a Value>, String> {
+    let mut current = vec![root];
+    for segment in parse(path)? {
+        current = current
+            .into_iter()
+            .flat_map(|v| -> Vec<&'a Value> {
+                match &segment {
+                    Segment::Field(key) => v.get(key).into_iter().collect(),
+                    Segment::Index(idx) => {
+                        let items = v.as_array().map(Vec::as_slice).unwrap_or_default();
+                        let idx = if *idx < 0 { items.len() as i64 + idx } else { *idx };
+                        usize::try_from(idx).ok().and_then(|i| items.get(i)).into_iter().collect()
+                    }
+                    Segment::Wildcard => match v {
+                        Value::Array(items) => items.iter().collect(),
+                        Value::Object(map) => map.values().collect(),
+                        _ => Vec::new(),
+                    },
+                    Segment::Filter { path, condition } => v
+                        .as_array()
+                        .map(|items| {
+                            items
+                                .iter()
+                                .filter(|item| match (lookup(item, path), condition) {
+                                    (Some(_), None) => true,
+                                    (Some(found), Some((eq, expected))) => (found == expected) == *eq,
+                                    (None, Some((false, _))) => true,
+                                    (None, _) => false,
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                }
+            })
+            .collect();
+    }
+    Ok(current)
+}
+
+/// Render matched values the way kubectl prints a column: scalars as-is, several matches
+/// comma-separated, and objects/arrays as compact JSON.
+pub fn render(values: &[&Value]) -> String {
+    values
+        .iter()
+        .map(|v| match v {
+            Value::String(s) => s.clone(),
+            Value::Null => String::new(),
+            other => other.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_evaluate_printer_column_paths() {
+        let obj = json!({
+            "spec": { "replicas": 3, "hosts": ["a", "b"] },
+            "status": {
+                "conditions": [
+                    { "type": "Ready", "status": "True" },
+                    { "type": "Synced", "status": "False" }
+                ]
+            }
+        });
+        let eval = |p: &str| render(&evaluate(&obj, p).unwrap());
+
+        assert_eq!(eval(".spec.replicas"), "3");
+        assert_eq!(eval("{.spec.hosts[*]}"), "a,b");
+        assert_eq!(eval(".spec.hosts[-1]"), "b");
+        assert_eq!(eval(".status.conditions[?(@.type==\"Ready\")].status"), "True");
+        assert_eq!(eval(".status.conditions[?(@.type!='Ready')].type"), "Synced");
+        assert_eq!(eval(".spec['replicas']"), "3");
+        assert_eq!(eval(".status.missing"), "");
+        assert!(evaluate(&obj, ".spec[").is_err());
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::BTreeMap;
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use serde_json::{Map, Value};
+
+use crate::domain::entities::MetadataEdit;
+use crate::domain::errors::DomainError;
+
+/// Upper bound the API server enforces on the combined size of an object's annotations.
+const MAX_ANNOTATIONS_BYTES: usize = 256 * 1024;
+
+/// DNS-1123 subdomain: lowercase alphanumerics, '-' and '.', at most 253 characters.
+pub fn is_dns_subdomain(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= 253
+        && value.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '.')
+        && value.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && value.ends_with(|c: char| c.is_ascii_alphanumeric())
+}
+
+/// DNS-1123 label, as required for Namespace, Service and container names: lowercase
+/// alphanumerics and '-', at most 63 characters, no dots.
+pub fn is_dns_label(value: &str) -> bool {
+    value.len() <= 63 && !value.contains('.') && is_dns_subdomain(value)
+}
+
+/// The name segment shared by qualified names and label values: at most 63 characters,
+/// alphanumerics plus '-', '_' and '.', beginning and ending with an alphanumeric.
+fn is_name_segment(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= 63
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+        && value.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && value.ends_with(|c: char| c.is_ascii_alphanumeric())
+}
+
+/// Check a label or annotation key: an optional DNS subdomain prefix and '/', then a name.
+pub fn validate_key(key: &str) -> Result<(), String> {
+    let (prefix, name) = match key.split_once('/') {
+        Some((prefix, name)) => (Some(prefix), name),
+        None => (None, key),
+    };
+    if let Some(prefix) = prefix {
+        if !is_dns_subdomain(prefix) {
+            return Err(format!(
+                "key \"{}\": prefix must be a lowercase DNS subdomain of at most 253 characters",
+                key
+            ));
+        }
+    }
+    if !is_name_segment(name) {
+        return Err(format!(
+            "key \"{}\": name must be 1-63 alphanumerics, '-', '_' or '.', starting and ending with an alphanumeric",
+            key
+        ));
+    }
+    Ok(())
+}
+
+pub fn validate_label_value(key: &str, value: &str) -> Result<(), String> {
+    if value.is_empty() || is_name_segment(value) {
+        Ok(())
+    } else {
+        Err(format!(
+            "label \"{}\": value must be empty or 1-63 alphanumerics, '-', '_' or '.', starting and ending with an alphanumeric",
+            key
+        ))
+    }
+}
+
+/// Evaluate a `LabelSelector` (matchLabels + matchExpressions) against a label set.
+/// An empty selector matches everything, mirroring the API server semantics.
+pub fn label_selector_matches(selector: &LabelSelector, labels: &BTreeMap<String, String>) -> bool {
+    let labels_ok = selector
+        .match_labels
+        .as_ref()
+        .map(|ml| ml.iter().all(|(k, v)| labels.get(k) == Some(v)))
+        .unwrap_or(true);
+    if !labels_ok {
+        return false;
+    }
+
+    selector
+        .match_expressions
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .all(|req| {
+            let values = req.values.as_deref().unwrap_or_default();
+            match req.operator.as_str() {
+                "In" => labels.get(&req.key).map(|v| values.contains(v)).unwrap_or(false),
+                "NotIn" => labels.get(&req.key).map(|v| !values.contains(v)).unwrap_or(true),
+                "Exists" => labels.contains_key(&req.key),
+                "DoesNotExist" => !labels.contains_key(&req.key),
+                _ => false,
+            }
+        })
+}
+
+fn validate_edit(edit: &MetadataEdit, labels: bool) -> Vec<String> {
+    let mut errors = Vec::new();
+    for (key, value) in &edit.set {
+        if let Err(e) = validate_key(key) {
+            errors.push(e);
+        }
+        if labels {
+            if let Err(e) = validate_label_value(key, value) {
+                errors.push(e);
+            }
+        }
+        if edit.remove.contains(key) {
+            errors.push(format!("key \"{}\" is both set and removed", key));
+        }
+    }
+    for key in &edit.remove {
+        if let Err(e) = validate_key(key) {
+            errors.push(e);
+        }
+    }
+    if !labels {
+        let size: usize = edit.set.iter().map(|(k, v)| k.len() + v.len()).sum();
+        if size > MAX_ANNOTATIONS_BYTES {
+            errors.push(format!("annotations total {} bytes, more than the 256 KiB limit", size));
+        }
+    }
+    errors
+}
+
+fn edit_map(edit: &MetadataEdit) -> Map<String, Value> {
+    let mut map: Map<String, Value> = edit
+        .set
+        .iter()
+        .map(|(k, v)| (k.clone(), Value::String(v.clone())))
+        .collect();
+    for key in &edit.remove {
+        map.insert(key.clone(), Value::Null);
+    }
+    map
+}
+
+/// Validate label and annotation edits and build the JSON merge patch applying them;
+/// removed keys are sent as `null`.
+pub fn build_metadata_patch(labels: &MetadataEdit, annotations: &MetadataEdit) -> Result<Value, DomainError> {
+    let mut errors = validate_edit(labels, true);
+    errors.extend(validate_edit(annotations, false));
+    if !errors.is_empty() {
+        return Err(DomainError::Configuration(errors.join("; ")));
+    }
+
+    let mut metadata = Map::new();
+    if !labels.set.is_empty() || !labels.remove.is_empty() {
+        metadata.insert("labels".to_string(), Value::Object(edit_map(labels)));
+    }
+    if !annotations.set.is_empty() || !annotations.remove.is_empty() {
+        metadata.insert("annotations".to_string(), Value::Object(edit_map(annotations)));
+    }
+    if metadata.is_empty() {
+        return Err(DomainError::Configuration("No label or annotation changes".to_string()));
+    }
+    Ok(serde_json::json!({ "metadata": metadata }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement;
+    use serde_json::json;
+
+    fn edit(set: &[(&str, &str)], remove: &[&str]) -> MetadataEdit {
+        MetadataEdit {
+            set: set.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            remove: remove.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+
+    fn labels(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_empty_selector_matches_everything() {
+        let sel = LabelSelector::default();
+        assert!(label_selector_matches(&sel, &labels(&[])));
+        assert!(label_selector_matches(&sel, &labels(&[("app", "web")])));
+    }
+
+    #[test]
+    fn test_match_labels() {
+        let sel = LabelSelector {
+            match_labels: Some(labels(&[("app", "web")])),
+            ..Default::default()
+        };
+        assert!(label_selector_matches(&sel, &labels(&[("app", "web"), ("tier", "fe")])));
+        assert!(!label_selector_matches(&sel, &labels(&[("app", "api")])));
+    }
+
+    #[test]
+    fn test_match_expressions() {
+        let sel = LabelSelector {
+            match_expressions: Some(vec![
+                LabelSelectorRequirement {
+                    key: "env".to_string(),
+                    operator: "In".to_string(),
+                    values: Some(vec!["prod".to_string(), "staging".to_string()]),
+                },
+                LabelSelectorRequirement {
+                    key: "canary".to_string(),
+                    operator: "DoesNotExist".to_string(),
+                    values: None,
+                },
+            ]),
+            ..Default::default()
+        };
+        assert!(label_selector_matches(&sel, &labels(&[("env", "prod")])));
+        assert!(!label_selector_matches(&sel, &labels(&[("env", "dev")])));
+        assert!(!label_selector_matches(&sel, &labels(&[("env", "prod"), ("canary", "true")])));
+    }
+
+    #[test]
+    fn test_validate_key() {
+        assert!(validate_key("app").is_ok());
+        assert!(validate_key("app.kubernetes.io/name").is_ok());
+        assert!(validate_key("Team_Owner.v2").is_ok());
+        assert!(validate_key("").is_err());
+        assert!(validate_key("-app").is_err());
+        assert!(validate_key("Example.com/app").is_err());
+        assert!(validate_key("example.com/").is_err());
+        assert!(validate_key("a/b/c").is_err());
+        assert!(validate_key(&"x".repeat(64)).is_err());
+    }
+
+    #[test]
+    fn test_is_dns_label() {
+        assert!(is_dns_label("web-1"));
+        assert!(is_dns_label(&"a".repeat(63)));
+        assert!(!is_dns_label(&"a".repeat(64)));
+        assert!(!is_dns_label("my.app"));
+        assert!(!is_dns_label("-web"));
+        assert!(!is_dns_label("Web"));
+    }
+
+    #[test]
+    fn test_build_metadata_patch() {
+        let patch = build_metadata_patch(
+            &edit(&[("tier", "web"), ("empty", "")], &["old"]),
+            &edit(&[("note", "free text: anything goes!")], &[]),
+        )
+        .unwrap();
+        assert_eq!(
+            patch,
+            json!({
+                "metadata": {
+                    "labels": { "tier": "web", "empty": "", "old": null },
+                    "annotations": { "note": "free text: anything goes!" }
+                }
+            })
+        );
+
+        let err = build_metadata_patch(&edit(&[("tier", "has space")], &["tier"]), &MetadataEdit::default())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("value must be empty"));
+        assert!(err.contains("both set and removed"));
+
+        assert!(build_metadata_patch(&MetadataEdit::default(), &MetadataEdit::default()).is_err());
+    }
+}
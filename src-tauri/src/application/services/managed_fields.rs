@@ -0,0 +1,110 @@
+use serde_json::{Map, Value};
+
+use crate::application::services::yaml_positions::{join_index, join_key};
+
+/// Index of the list item a `k:` or `v:` key refers to. `k:` keys carry the item's merge
+/// key fields (e.g. `{"name":"web"}`), `v:` keys the whole value of a set member.
+fn find_item(items: Option<&Value>, key: &Value, by_fields: bool) -> Option<usize> {
+    items?.as_array()?.iter().position(|item| {
+        if by_fields {
+            key.as_object()
+                .is_some_and(|fields| fields.iter().all(|(k, v)| item.get(k) == Some(v)))
+        } else {
+            item == key
+        }
+    })
+}
+
+fn walk(fields: &Map<String, Value>, value: Option<&Value>, path: &str, out: &mut Vec<String>) {
+    for (key, child) in fields {
+        if key == "." {
+            out.push(path.to_string());
+            continue;
+        }
+        let Some((prefix, rest)) = key.split_once(':') else { continue };
+        let (child_path, child_value) = match prefix {
+            "f" => (join_key(path, rest), value.and_then(|v| v.get(rest))),
+            "i" => {
+                let Ok(idx) = rest.parse::<usize>() else { continue };
+                (join_index(path, idx), value.and_then(|v| v.get(idx)))
+            }
+            "k" | "v" => {
+                let Ok(item_key) = serde_json::from_str::<Value>(rest) else { continue };
+                match find_item(value, &item_key, prefix == "k") {
+                    Some(idx) => (join_index(path, idx), value.and_then(|v| v.get(idx))),
+                    // No longer present in the object; keep the raw key so it can still be shown
+                    None => (format!("{}[{}]", path, rest), None),
+                }
+            }
+            _ => continue,
+        };
+        match child.as_object() {
+            Some(grandchildren) if !grandchildren.is_empty() => walk(grandchildren, child_value, &child_path, out),
+            _ => out.push(child_path),
+        }
+    }
+}
+
+/// Expand a managedFields `fieldsV1` trie into the paths it owns, written the same way
+/// `YamlPositions` indexes them (`spec.template.spec.containers[0].image`). List items
+/// identified by merge key or value are mapped to their index in `obj`.
+pub fn owned_paths(fields_v1: &Value, obj: &Value) -> Vec<String> {
+    let mut out = Vec::new();
+    if let Some(fields) = fields_v1.as_object() {
+        walk(fields, Some(obj), "", &mut out);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_owned_paths() {
+        let obj = json!({
+            "metadata": { "labels": { "app": "web" }, "finalizers": ["a", "b"] },
+            "spec": {
+                "replicas": 3,
+                "template": { "spec": { "containers": [
+                    { "name": "sidecar", "image": "envoy" },
+                    { "name": "web", "image": "nginx", "ports": [{ "containerPort": 80, "protocol": "TCP" }] }
+                ] } }
+            }
+        });
+        let fields = json!({
+            "f:metadata": {
+                "f:labels": { ".": {}, "f:app": {} },
+                "f:finalizers": { "v:\"b\"": {} }
+            },
+            "f:spec": {
+                "f:replicas": {},
+                "f:template": { "f:spec": { "f:containers": {
+                    "k:{\"name\":\"web\"}": {
+                        ".": {},
+                        "f:image": {},
+                        "f:ports": { "k:{\"containerPort\":80,\"protocol\":\"TCP\"}": { "f:containerPort": {} } }
+                    },
+                    "k:{\"name\":\"gone\"}": { "f:image": {} }
+                } } }
+            }
+        });
+
+        let mut paths = owned_paths(&fields, &obj);
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                "metadata.finalizers[1]",
+                "metadata.labels",
+                "metadata.labels.app",
+                "spec.replicas",
+                "spec.template.spec.containers[1]",
+                "spec.template.spec.containers[1].image",
+                "spec.template.spec.containers[1].ports[0].containerPort",
+                "spec.template.spec.containers[{\"name\":\"gone\"}].image",
+            ]
+        );
+    }
+}
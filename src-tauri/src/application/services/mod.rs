@@ -1,2 +1,12 @@
 pub mod config_db;
+pub mod cron;
+pub mod diff;
+pub mod finalizers;
 pub mod formatting;
+pub mod jsonpath;
+pub mod labels;
+pub mod managed_fields;
+pub mod network_policy_analyzer;
+pub mod schema_validator;
+pub mod templates;
+pub mod yaml_positions;
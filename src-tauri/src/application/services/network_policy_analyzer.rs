@@ -0,0 +1,351 @@
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+
+use k8s_openapi::api::networking::v1::{NetworkPolicy, NetworkPolicyPeer, NetworkPolicyPort};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+use crate::application::services::labels::label_selector_matches;
+use crate::domain::entities::PolicyVerdict;
+
+/// Everything the analyzer needs to know about one side of a connection.
+#[derive(Debug, Clone, Default)]
+pub struct PodEndpoint {
+    pub name: String,
+    pub namespace: String,
+    pub labels: BTreeMap<String, String>,
+    pub namespace_labels: BTreeMap<String, String>,
+    pub ip: Option<String>,
+    /// Container ports as `(name, port, protocol)`, used to resolve named ports.
+    pub named_ports: Vec<(String, i32, String)>,
+}
+
+/// Policy types a policy applies to. When `policyTypes` is unset the API server
+/// assumes `Ingress`, plus `Egress` if the policy has any egress rules.
+pub fn effective_policy_types(policy: &NetworkPolicy) -> Vec<String> {
+    let spec = match &policy.spec {
+        Some(s) => s,
+        None => return vec!["Ingress".to_string()],
+    };
+    if let Some(types) = &spec.policy_types {
+        if !types.is_empty() {
+            return types.clone();
+        }
+    }
+    let mut types = vec!["Ingress".to_string()];
+    if spec.egress.as_ref().map(|e| !e.is_empty()).unwrap_or(false) {
+        types.push("Egress".to_string());
+    }
+    types
+}
+
+fn policy_selects(policy: &NetworkPolicy, pod: &PodEndpoint) -> bool {
+    policy.metadata.namespace.as_deref() == Some(pod.namespace.as_str())
+        && policy
+            .spec
+            .as_ref()
+            .map(|s| label_selector_matches(&s.pod_selector, &pod.labels))
+            .unwrap_or(false)
+}
+
+fn ip_in_cidr(ip: &IpAddr, cidr: &str) -> bool {
+    let (net, prefix) = match cidr.split_once('/') {
+        Some((n, p)) => (n, p.parse::<u32>().ok()),
+        None => (cidr, None),
+    };
+    let net: IpAddr = match net.parse() {
+        Ok(n) => n,
+        Err(_) => return false,
+    };
+    match (ip, net) {
+        (IpAddr::V4(a), IpAddr::V4(b)) => {
+            let prefix = prefix.unwrap_or(32).min(32);
+            let mask = if prefix == 0 { 0 } else { u32::MAX << (32 - prefix) };
+            (u32::from(*a) & mask) == (u32::from(b) & mask)
+        }
+        (IpAddr::V6(a), IpAddr::V6(b)) => {
+            let prefix = prefix.unwrap_or(128).min(128);
+            let mask = if prefix == 0 { 0 } else { u128::MAX << (128 - prefix) };
+            (u128::from(*a) & mask) == (u128::from(b) & mask)
+        }
+        _ => false,
+    }
+}
+
+/// Whether `peer` (from a policy living in `policy_namespace`) matches `pod`.
+fn peer_matches(peer: &NetworkPolicyPeer, policy_namespace: &str, pod: &PodEndpoint) -> bool {
+    if let Some(block) = &peer.ip_block {
+        let ip: IpAddr = match pod.ip.as_deref().and_then(|i| i.parse().ok()) {
+            Some(ip) => ip,
+            None => return false,
+        };
+        let excluded = block
+            .except
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .any(|e| ip_in_cidr(&ip, e));
+        return ip_in_cidr(&ip, &block.cidr) && !excluded;
+    }
+
+    let ns_ok = match &peer.namespace_selector {
+        Some(sel) => label_selector_matches(sel, &pod.namespace_labels),
+        None => pod.namespace == policy_namespace,
+    };
+    let pod_ok = peer
+        .pod_selector
+        .as_ref()
+        .map(|sel| label_selector_matches(sel, &pod.labels))
+        .unwrap_or(true);
+    ns_ok && pod_ok
+}
+
+fn port_matches(rule_port: &NetworkPolicyPort, port: i32, protocol: &str, destination: &PodEndpoint) -> bool {
+    let rule_protocol = rule_port.protocol.as_deref().unwrap_or("TCP");
+    if !rule_protocol.eq_ignore_ascii_case(protocol) {
+        return false;
+    }
+    match &rule_port.port {
+        None => true,
+        Some(IntOrString::Int(p)) => match rule_port.end_port {
+            Some(end) => port >= *p && port <= end,
+            None => port == *p,
+        },
+        Some(IntOrString::String(name)) => destination
+            .named_ports
+            .iter()
+            .any(|(n, p, proto)| n == name && *p == port && proto.eq_ignore_ascii_case(protocol)),
+    }
+}
+
+fn ports_match(ports: Option<&Vec<NetworkPolicyPort>>, port: i32, protocol: &str, destination: &PodEndpoint) -> bool {
+    match ports {
+        Some(ps) if !ps.is_empty() => ps.iter().any(|p| port_matches(p, port, protocol, destination)),
+        _ => true,
+    }
+}
+
+fn peers_match(peers: Option<&Vec<NetworkPolicyPeer>>, policy_namespace: &str, pod: &PodEndpoint) -> bool {
+    match peers {
+        Some(ps) if !ps.is_empty() => ps.iter().any(|p| peer_matches(p, policy_namespace, pod)),
+        _ => true,
+    }
+}
+
+/// Evaluate one direction. `subject` is the pod the policies select (source for
+/// egress, destination for ingress); `peer` is the other end of the connection.
+fn evaluate_direction(
+    policies: &[NetworkPolicy],
+    direction: &str,
+    subject: &PodEndpoint,
+    peer: &PodEndpoint,
+    destination: &PodEndpoint,
+    port: i32,
+    protocol: &str,
+) -> PolicyVerdict {
+    let selecting: Vec<&NetworkPolicy> = policies
+        .iter()
+        .filter(|p| policy_selects(p, subject) && effective_policy_types(p).iter().any(|t| t == direction))
+        .collect();
+    let selecting_policies: Vec<String> = selecting
+        .iter()
+        .map(|p| p.metadata.name.clone().unwrap_or_default())
+        .collect();
+
+    if selecting.is_empty() {
+        return PolicyVerdict {
+            allowed: true,
+            isolated: false,
+            selecting_policies,
+            matched_policy: None,
+            matched_rule: None,
+            reason: format!(
+                "No {} policy selects {}/{}; {} traffic is not isolated",
+                direction, subject.namespace, subject.name, direction.to_lowercase()
+            ),
+        };
+    }
+
+    for policy in &selecting {
+        let policy_name = policy.metadata.name.clone().unwrap_or_default();
+        let policy_ns = policy.metadata.namespace.clone().unwrap_or_default();
+        let spec = match &policy.spec {
+            Some(s) => s,
+            None => continue,
+        };
+        let matched = if direction == "Ingress" {
+            spec.ingress.as_deref().unwrap_or_default().iter().position(|r| {
+                peers_match(r.from.as_ref(), &policy_ns, peer)
+                    && ports_match(r.ports.as_ref(), port, protocol, destination)
+            })
+        } else {
+            spec.egress.as_deref().unwrap_or_default().iter().position(|r| {
+                peers_match(r.to.as_ref(), &policy_ns, peer)
+                    && ports_match(r.ports.as_ref(), port, protocol, destination)
+            })
+        };
+        if let Some(idx) = matched {
+            return PolicyVerdict {
+                allowed: true,
+                isolated: true,
+                selecting_policies,
+                matched_policy: Some(policy_name.clone()),
+                matched_rule: Some(idx as i32),
+                reason: format!(
+                    "{} rule #{} of policy {} permits {}/{} on {}/{}",
+                    direction, idx + 1, policy_name, peer.namespace, peer.name, port, protocol
+                ),
+            };
+        }
+    }
+
+    let reason = format!(
+        "{}/{} is isolated for {} by {} and no rule permits {}/{} on {}/{}",
+        subject.namespace,
+        subject.name,
+        direction.to_lowercase(),
+        selecting_policies.join(", "),
+        peer.namespace,
+        peer.name,
+        port,
+        protocol
+    );
+    PolicyVerdict {
+        allowed: false,
+        isolated: true,
+        selecting_policies,
+        matched_policy: None,
+        matched_rule: None,
+        reason,
+    }
+}
+
+/// Evaluate whether `source` can open a connection to `destination` on `port`/`protocol`.
+/// `policies` should contain the policies of both namespaces; only those selecting the
+/// relevant pod are considered. Returns `(egress, ingress)` verdicts.
+pub fn evaluate(
+    policies: &[NetworkPolicy],
+    source: &PodEndpoint,
+    destination: &PodEndpoint,
+    port: i32,
+    protocol: &str,
+) -> (PolicyVerdict, PolicyVerdict) {
+    let egress = evaluate_direction(policies, "Egress", source, destination, destination, port, protocol);
+    let ingress = evaluate_direction(policies, "Ingress", destination, source, destination, port, protocol);
+    (egress, ingress)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::networking::v1::{IPBlock, NetworkPolicyIngressRule, NetworkPolicySpec};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
+
+    fn labels(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    fn pod(name: &str, ns: &str, pod_labels: &[(&str, &str)], ip: &str) -> PodEndpoint {
+        PodEndpoint {
+            name: name.to_string(),
+            namespace: ns.to_string(),
+            labels: labels(pod_labels),
+            namespace_labels: labels(&[("kubernetes.io/metadata.name", ns)]),
+            ip: Some(ip.to_string()),
+            named_ports: vec![("http".to_string(), 8080, "TCP".to_string())],
+        }
+    }
+
+    fn ingress_policy(name: &str, ns: &str, target: &[(&str, &str)], rules: Vec<NetworkPolicyIngressRule>) -> NetworkPolicy {
+        NetworkPolicy {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some(ns.to_string()),
+                ..Default::default()
+            },
+            spec: Some(NetworkPolicySpec {
+                pod_selector: LabelSelector {
+                    match_labels: Some(labels(target)),
+                    ..Default::default()
+                },
+                ingress: Some(rules),
+                policy_types: Some(vec!["Ingress".to_string()]),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_unselected_pods_are_open() {
+        let src = pod("client", "default", &[("app", "client")], "10.0.0.1");
+        let dst = pod("web", "default", &[("app", "web")], "10.0.0.2");
+        let (egress, ingress) = evaluate(&[], &src, &dst, 80, "TCP");
+        assert!(egress.allowed && !egress.isolated);
+        assert!(ingress.allowed && !ingress.isolated);
+    }
+
+    #[test]
+    fn test_deny_all_blocks_ingress() {
+        let src = pod("client", "default", &[("app", "client")], "10.0.0.1");
+        let dst = pod("web", "default", &[("app", "web")], "10.0.0.2");
+        let policies = vec![ingress_policy("deny-all", "default", &[], vec![])];
+        let (_, ingress) = evaluate(&policies, &src, &dst, 80, "TCP");
+        assert!(!ingress.allowed);
+        assert_eq!(ingress.selecting_policies, vec!["deny-all"]);
+    }
+
+    #[test]
+    fn test_named_port_and_pod_selector_rule() {
+        let src = pod("client", "default", &[("app", "client")], "10.0.0.1");
+        let dst = pod("web", "default", &[("app", "web")], "10.0.0.2");
+        let rule = NetworkPolicyIngressRule {
+            from: Some(vec![NetworkPolicyPeer {
+                pod_selector: Some(LabelSelector {
+                    match_labels: Some(labels(&[("app", "client")])),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }]),
+            ports: Some(vec![NetworkPolicyPort {
+                port: Some(IntOrString::String("http".to_string())),
+                ..Default::default()
+            }]),
+        };
+        let policies = vec![ingress_policy("allow-client", "default", &[("app", "web")], vec![rule])];
+
+        let (_, ingress) = evaluate(&policies, &src, &dst, 8080, "TCP");
+        assert!(ingress.allowed);
+        assert_eq!(ingress.matched_policy.as_deref(), Some("allow-client"));
+        assert_eq!(ingress.matched_rule, Some(0));
+
+        let (_, ingress) = evaluate(&policies, &src, &dst, 9090, "TCP");
+        assert!(!ingress.allowed);
+
+        // Same labels in another namespace do not match a peer without namespaceSelector.
+        let other = pod("client", "other", &[("app", "client")], "10.0.1.1");
+        let (_, ingress) = evaluate(&policies, &other, &dst, 8080, "TCP");
+        assert!(!ingress.allowed);
+    }
+
+    #[test]
+    fn test_ip_block_with_except() {
+        let src = pod("client", "default", &[], "10.1.2.3");
+        let dst = pod("web", "default", &[("app", "web")], "10.0.0.2");
+        let rule = NetworkPolicyIngressRule {
+            from: Some(vec![NetworkPolicyPeer {
+                ip_block: Some(IPBlock {
+                    cidr: "10.0.0.0/8".to_string(),
+                    except: Some(vec!["10.1.0.0/16".to_string()]),
+                }),
+                ..Default::default()
+            }]),
+            ports: None,
+        };
+        let policies = vec![ingress_policy("cidr", "default", &[("app", "web")], vec![rule])];
+        let (_, ingress) = evaluate(&policies, &src, &dst, 80, "TCP");
+        assert!(!ingress.allowed);
+
+        let allowed_src = pod("client", "default", &[], "10.2.0.1");
+        let (_, ingress) = evaluate(&policies, &allowed_src, &dst, 80, "TCP");
+        assert!(ingress.allowed);
+    }
+}
@@ -0,0 +1,209 @@
+use serde_json::Value;
+
+use crate::application::services::yaml_positions::{join_index, join_key};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    pub path: String,
+    pub issue_type: &'static str,
+    pub message: String,
+}
+
+const MAX_DEPTH: usize = 64;
+
+/// Follow `$ref`s into `components` and unwrap the single-element `allOf` the API server
+/// uses to attach defaults to a reference.
+fn deref<'a>(mut schema: &'a Value, components: &'a Value) -> &'a Value {
+    for _ in 0..MAX_DEPTH {
+        if let Some(reference) = schema.get("$ref").and_then(|r| r.as_str()) {
+            match reference
+                .strip_prefix("#/components/schemas/")
+                .and_then(|name| components.get(name))
+            {
+                Some(target) => {
+                    schema = target;
+                    continue;
+                }
+                None => return schema,
+            }
+        }
+        match schema.get("allOf").and_then(|a| a.as_array()).map(Vec::as_slice) {
+            Some([only]) => schema = only,
+            _ => return schema,
+        }
+    }
+    schema
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn type_matches(value: &Value, schema: &Value) -> bool {
+    if value.is_null() {
+        return true;
+    }
+    let int_or_string = schema["x-kubernetes-int-or-string"] == true || schema["format"] == "int-or-string";
+    if int_or_string {
+        return value.is_string() || value.is_i64() || value.is_u64();
+    }
+    match schema.get("type").and_then(|t| t.as_str()) {
+        Some("string") => value.is_string(),
+        Some("integer") => value.is_i64() || value.is_u64(),
+        Some("number") => value.is_number(),
+        Some("boolean") => value.is_boolean(),
+        Some("object") => value.is_object(),
+        Some("array") => value.is_array(),
+        _ => true,
+    }
+}
+
+fn expected_type(schema: &Value) -> String {
+    if schema["x-kubernetes-int-or-string"] == true || schema["format"] == "int-or-string" {
+        return "integer or string".to_string();
+    }
+    schema.get("type").and_then(|t| t.as_str()).unwrap_or("any").to_string()
+}
+
+fn walk(value: &Value, schema: &Value, components: &Value, path: &str, depth: usize, out: &mut Vec<Violation>) {
+    let schema = deref(schema, components);
+    if depth > MAX_DEPTH || value.is_null() {
+        return;
+    }
+
+    // e.g. Quantity is published as oneOf string/number
+    if let Some(branches) = schema.get("oneOf").or_else(|| schema.get("anyOf")).and_then(|b| b.as_array()) {
+        let branches: Vec<&Value> = branches.iter().map(|b| deref(b, components)).collect();
+        if !branches.iter().any(|b| type_matches(value, b)) {
+            let expected: Vec<String> = branches.iter().map(|b| expected_type(b)).collect();
+            out.push(Violation {
+                path: path.to_string(),
+                issue_type: "type_mismatch",
+                message: format!("expected {}, got {}", expected.join(" or "), type_name(value)),
+            });
+        }
+        return;
+    }
+
+    if !type_matches(value, schema) {
+        out.push(Violation {
+            path: path.to_string(),
+            issue_type: "type_mismatch",
+            message: format!("expected {}, got {}", expected_type(schema), type_name(value)),
+        });
+        return;
+    }
+
+    match value {
+        Value::Object(map) => {
+            for required in schema.get("required").and_then(|r| r.as_array()).into_iter().flatten() {
+                let Some(field) = required.as_str() else { continue };
+                if !map.contains_key(field) {
+                    out.push(Violation {
+                        path: join_key(path, field),
+                        issue_type: "missing_required",
+                        message: format!("missing required field \"{}\"", field),
+                    });
+                }
+            }
+
+            let properties = schema.get("properties").and_then(|p| p.as_object());
+            let additional = schema.get("additionalProperties");
+            let preserve_unknown = schema["x-kubernetes-preserve-unknown-fields"] == true;
+            for (key, child) in map {
+                let child_path = join_key(path, key);
+                if let Some(child_schema) = properties.and_then(|p| p.get(key)) {
+                    walk(child, child_schema, components, &child_path, depth + 1, out);
+                } else if let Some(child_schema) = additional.filter(|a| a.is_object()) {
+                    walk(child, child_schema, components, &child_path, depth + 1, out);
+                } else if properties.is_some() && !preserve_unknown && additional != Some(&Value::Bool(true)) {
+                    out.push(Violation {
+                        path: child_path,
+                        issue_type: "unknown_field",
+                        message: format!("unknown field \"{}\"", key),
+                    });
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    walk(item, item_schema, components, &join_index(path, i), depth + 1, out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Check `value` against an OpenAPI v3 `schema`, resolving `$ref`s against `components`
+/// (the document's `components.schemas`; `Value::Null` for self-contained CRD schemas).
+pub fn validate(value: &Value, schema: &Value, components: &Value) -> Vec<Violation> {
+    let mut out = Vec::new();
+    walk(value, schema, components, "", 0, &mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_validate_reports_each_issue_type() {
+        let components = json!({
+            "Container": {
+                "type": "object",
+                "required": ["name"],
+                "properties": {
+                    "name": { "type": "string" },
+                    "image": { "type": "string" },
+                    "port": { "type": "string", "format": "int-or-string" },
+                    "memory": { "oneOf": [{ "type": "string" }, { "type": "number" }] }
+                }
+            }
+        });
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "replicas": { "type": "integer" },
+                "labels": { "type": "object", "additionalProperties": { "type": "string" } },
+                "containers": { "type": "array", "items": { "allOf": [{ "$ref": "#/components/schemas/Container" }] } },
+                "extra": { "type": "object", "x-kubernetes-preserve-unknown-fields": true }
+            }
+        });
+        let value = json!({
+            "replicas": "3",
+            "labels": { "app": "web", "tier": 1 },
+            "containers": [
+                { "name": "web", "image": "nginx", "port": 8080, "memory": "1Gi", "imagePullPolicy": "Always" },
+                { "image": "busybox", "memory": true }
+            ],
+            "extra": { "anything": { "goes": 1 } }
+        });
+
+        let mut found: Vec<(String, &str)> = validate(&value, &schema, &components)
+            .into_iter()
+            .map(|v| (v.path, v.issue_type))
+            .collect();
+        found.sort();
+        assert_eq!(
+            found,
+            vec![
+                ("containers[0].imagePullPolicy".to_string(), "unknown_field"),
+                ("containers[1].memory".to_string(), "type_mismatch"),
+                ("containers[1].name".to_string(), "missing_required"),
+                ("labels.tier".to_string(), "type_mismatch"),
+                ("replicas".to_string(), "type_mismatch"),
+            ]
+        );
+    }
+}
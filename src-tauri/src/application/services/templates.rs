@@ -0,0 +1,443 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::application::services::config_db::ConfigDB;
+use crate::application::services::labels::is_dns_label;
+use crate::domain::entities::template::{ResourceTemplate, TemplateVariable};
+use crate::domain::errors::DomainError;
+
+/// ConfigDB key holding user-defined templates as a JSON array.
+const USER_TEMPLATES_KEY: &str = "resource_templates";
+
+fn var(name: &str, description: &str, default: &str) -> TemplateVariable {
+    TemplateVariable {
+        name: name.to_string(),
+        description: description.to_string(),
+        default: default.to_string(),
+    }
+}
+
+fn builtin(id: &str, kind: &str, description: &str, variables: Vec<TemplateVariable>, body: &str) -> ResourceTemplate {
+    let mut all = vec![var("name", "Resource name", ""), var("namespace", "Target namespace", "")];
+    all.extend(variables);
+    ResourceTemplate {
+        id: id.to_string(),
+        name: kind.to_string(),
+        description: description.to_string(),
+        kind: kind.to_string(),
+        builtin: true,
+        variables: all,
+        body: body.trim_start().to_string(),
+    }
+}
+
+pub fn builtin_templates() -> Vec<ResourceTemplate> {
+    vec![
+        builtin(
+            "builtin-deployment",
+            "Deployment",
+            "Stateless app with a single container",
+            vec![var("image", "Container image", "nginx:latest"), var("port", "Container port", "80"), var("replicas", "Replica count", "1")],
+            r#"
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: {{name}}
+  namespace: {{namespace}}
+  labels:
+    app: {{name}}
+spec:
+  replicas: {{replicas}}
+  selector:
+    matchLabels:
+      app: {{name}}
+  template:
+    metadata:
+      labels:
+        app: {{name}}
+    spec:
+      containers:
+      - name: {{name}}
+        image: {{image}}
+        ports:
+        - containerPort: {{port}}
+        resources:
+          requests:
+            cpu: 100m
+            memory: 128Mi
+          limits:
+            memory: 256Mi
+"#,
+        ),
+        builtin(
+            "builtin-service",
+            "Service",
+            "ClusterIP service selecting pods by app label",
+            vec![var("port", "Service port", "80"), var("target_port", "Container port", "80")],
+            r#"
+apiVersion: v1
+kind: Service
+metadata:
+  name: {{name}}
+  namespace: {{namespace}}
+spec:
+  type: ClusterIP
+  selector:
+    app: {{name}}
+  ports:
+  - name: http
+    port: {{port}}
+    targetPort: {{target_port}}
+    protocol: TCP
+"#,
+        ),
+        builtin(
+            "builtin-ingress",
+            "Ingress",
+            "HTTP ingress routing a host to a service",
+            vec![var("host", "Hostname", "example.local"), var("service", "Backend service", ""), var("port", "Service port", "80")],
+            r#"
+apiVersion: networking.k8s.io/v1
+kind: Ingress
+metadata:
+  name: {{name}}
+  namespace: {{namespace}}
+spec:
+  rules:
+  - host: {{host}}
+    http:
+      paths:
+      - path: /
+        pathType: Prefix
+        backend:
+          service:
+            name: {{service}}
+            port:
+              number: {{port}}
+"#,
+        ),
+        builtin(
+            "builtin-configmap",
+            "ConfigMap",
+            "Key/value configuration",
+            vec![var("key", "Data key", "config"), var("value", "Data value", "")],
+            r#"
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: {{name}}
+  namespace: {{namespace}}
+data:
+  {{key}}: "{{value}}"
+"#,
+        ),
+        builtin(
+            "builtin-secret",
+            "Secret",
+            "Opaque secret; values are written as stringData",
+            vec![var("key", "Data key", "password"), var("value", "Secret value", "")],
+            r#"
+apiVersion: v1
+kind: Secret
+metadata:
+  name: {{name}}
+  namespace: {{namespace}}
+type: Opaque
+stringData:
+  {{key}}: "{{value}}"
+"#,
+        ),
+        builtin(
+            "builtin-job",
+            "Job",
+            "Run-to-completion job",
+            vec![var("image", "Container image", "busybox:latest"), var("command", "Shell command", "echo hello")],
+            r#"
+apiVersion: batch/v1
+kind: Job
+metadata:
+  name: {{name}}
+  namespace: {{namespace}}
+spec:
+  backoffLimit: 3
+  template:
+    spec:
+      restartPolicy: Never
+      containers:
+      - name: {{name}}
+        image: {{image}}
+        command: ["sh", "-c", "{{command}}"]
+"#,
+        ),
+        builtin(
+            "builtin-cronjob",
+            "CronJob",
+            "Job run on a cron schedule",
+            vec![
+                var("image", "Container image", "busybox:latest"),
+                var("schedule", "Cron schedule", "*/5 * * * *"),
+                var("command", "Shell command", "echo hello"),
+            ],
+            r#"
+apiVersion: batch/v1
+kind: CronJob
+metadata:
+  name: {{name}}
+  namespace: {{namespace}}
+spec:
+  schedule: "{{schedule}}"
+  concurrencyPolicy: Forbid
+  jobTemplate:
+    spec:
+      template:
+        spec:
+          restartPolicy: OnFailure
+          containers:
+          - name: {{name}}
+            image: {{image}}
+            command: ["sh", "-c", "{{command}}"]
+"#,
+        ),
+        builtin(
+            "builtin-networkpolicy",
+            "NetworkPolicy",
+            "Allow ingress to an app only from pods in the same namespace",
+            vec![var("port", "Allowed port", "80")],
+            r#"
+apiVersion: networking.k8s.io/v1
+kind: NetworkPolicy
+metadata:
+  name: {{name}}
+  namespace: {{namespace}}
+spec:
+  podSelector:
+    matchLabels:
+      app: {{name}}
+  policyTypes:
+  - Ingress
+  ingress:
+  - from:
+    - podSelector: {}
+    ports:
+    - protocol: TCP
+      port: {{port}}
+"#,
+        ),
+    ]
+}
+
+pub fn load_user_templates(config_db: &ConfigDB) -> Result<Vec<ResourceTemplate>, DomainError> {
+    match config_db.get(USER_TEMPLATES_KEY).map_err(DomainError::Configuration)? {
+        Some(json) => Ok(serde_json::from_str(&json)?),
+        None => Ok(Vec::new()),
+    }
+}
+
+pub fn store_user_templates(config_db: &ConfigDB, templates: &[ResourceTemplate]) -> Result<(), DomainError> {
+    let json = serde_json::to_string(templates)?;
+    config_db.set(USER_TEMPLATES_KEY, &json).map_err(DomainError::Configuration)
+}
+
+/// Names of the `{{var}}` placeholders in `body`, in order of first appearance.
+pub fn placeholders(body: &str) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    let mut out = Vec::new();
+    let mut rest = body;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else { break };
+        let name = rest[start + 2..start + 2 + len].trim().to_string();
+        if !name.is_empty() && seen.insert(name.clone()) {
+            out.push(name);
+        }
+        rest = &rest[start + 2 + len + 2..];
+    }
+    out
+}
+
+/// Stand-in for the `index`th placeholder while the body is parsed; plain alphanumerics,
+/// so it reads the same as a plain or a quoted scalar.
+fn marker(index: usize) -> String {
+    format!("K0TEMPLATEVAR{}X", index)
+}
+
+/// Whether a double-quoted scalar is open at the end of `line`.
+fn inside_double_quotes(line: &str) -> bool {
+    let mut open = false;
+    let mut escaped = false;
+    for c in line.chars() {
+        match c {
+            '\\' if open && !escaped => {
+                escaped = true;
+                continue;
+            }
+            '"' if !escaped => open = !open,
+            _ => {}
+        }
+        escaped = false;
+    }
+    open
+}
+
+/// A placeholder standing alone in a plain scalar keeps the value's YAML type, so
+/// `replicas: {{replicas}}` stays a number; anything else is inserted as a string.
+fn typed_value(value: &str, quoted: bool) -> serde_yaml::Value {
+    if !quoted {
+        if let Ok(typed @ (serde_yaml::Value::Number(_) | serde_yaml::Value::Bool(_))) =
+            serde_yaml::from_str::<serde_yaml::Value>(value)
+        {
+            return typed;
+        }
+    }
+    serde_yaml::Value::String(value.to_string())
+}
+
+fn substitute(node: &mut serde_yaml::Value, slots: &[(String, bool)]) {
+    match node {
+        serde_yaml::Value::String(s) => {
+            if let Some((_, (value, quoted))) = slots.iter().enumerate().find(|(i, _)| *s == marker(*i)) {
+                *node = typed_value(value, *quoted);
+            } else if s.contains("K0TEMPLATEVAR") {
+                for (i, (value, _)) in slots.iter().enumerate() {
+                    *s = s.replace(&marker(i), value);
+                }
+            }
+        }
+        serde_yaml::Value::Sequence(items) => items.iter_mut().for_each(|item| substitute(item, slots)),
+        serde_yaml::Value::Mapping(map) => {
+            let entries = std::mem::take(map);
+            for (mut key, mut value) in entries {
+                substitute(&mut key, slots);
+                substitute(&mut value, slots);
+                map.insert(key, value);
+            }
+        }
+        serde_yaml::Value::Tagged(tagged) => substitute(&mut tagged.value, slots),
+        _ => {}
+    }
+}
+
+/// Substitute `{{var}}` placeholders, taking values from `values` and falling back to the
+/// template's defaults; `name` and `namespace` must be valid object names. Placeholders
+/// are swapped for markers and the body parsed first, then the values are put into the
+/// parsed tree, so no value can change the document's structure whatever it contains.
+pub fn render(template: &ResourceTemplate, values: &BTreeMap<String, String>) -> Result<String, DomainError> {
+    let defaults: BTreeMap<&str, &str> = template
+        .variables
+        .iter()
+        .map(|v| (v.name.as_str(), v.default.as_str()))
+        .collect();
+
+    let mut resolved = BTreeMap::new();
+    let mut missing = Vec::new();
+    for name in placeholders(&template.body) {
+        let value = values
+            .get(&name)
+            .map(String::as_str)
+            .filter(|v| !v.is_empty())
+            .or_else(|| defaults.get(name.as_str()).copied().filter(|v| !v.is_empty()));
+        match value {
+            Some(v) => {
+                if (name == "name" || name == "namespace") && !is_dns_label(v) {
+                    return Err(DomainError::Configuration(format!(
+                        "{} \"{}\" must be at most 63 lowercase letters, digits or '-', starting and ending with an alphanumeric",
+                        name, v
+                    )));
+                }
+                resolved.insert(name, v.to_string());
+            }
+            None => missing.push(name),
+        }
+    }
+    if !missing.is_empty() {
+        return Err(DomainError::Configuration(format!("Missing values for: {}", missing.join(", "))));
+    }
+
+    let mut slots: Vec<(String, bool)> = Vec::new();
+    let mut marked = String::with_capacity(template.body.len());
+    let mut rest = template.body.as_str();
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else { break };
+        marked.push_str(&rest[..start]);
+        let name = rest[start + 2..start + 2 + len].trim();
+        match resolved.get(name) {
+            Some(value) => {
+                let line = &marked[marked.rfind('\n').map_or(0, |i| i + 1)..];
+                slots.push((value.clone(), inside_double_quotes(line)));
+                marked.push_str(&marker(slots.len() - 1));
+            }
+            None => marked.push_str(&rest[start..start + 2 + len + 2]),
+        }
+        rest = &rest[start + 2 + len + 2..];
+    }
+    marked.push_str(rest);
+
+    let mut doc: serde_yaml::Value = serde_yaml::from_str(&marked)
+        .map_err(|e| DomainError::Configuration(format!("Template body is not valid YAML: {}", e)))?;
+    substitute(&mut doc, &slots);
+    Ok(serde_yaml::to_string(&doc)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_builtins_render_to_valid_yaml() {
+        for template in builtin_templates() {
+            let yaml = render(&template, &values(&[("name", "web"), ("namespace", "default"), ("service", "web"), ("value", "x")]))
+                .unwrap_or_else(|e| panic!("{}: {}", template.id, e));
+            let doc: serde_json::Value = serde_yaml::from_str(&yaml).unwrap();
+            assert_eq!(doc["kind"], template.kind.as_str());
+            assert_eq!(doc["metadata"]["name"], "web");
+        }
+    }
+
+    #[test]
+    fn test_render_substitution_and_validation() {
+        let template = builtin_templates().into_iter().find(|t| t.kind == "Service").unwrap();
+        let yaml = render(&template, &values(&[("name", "api"), ("namespace", "prod"), ("port", "8080")])).unwrap();
+        assert!(yaml.contains("port: 8080"));
+        assert!(yaml.contains("targetPort: 80"));
+
+        assert!(render(&template, &values(&[("namespace", "prod")])).is_err());
+        assert!(render(&template, &values(&[("name", "Bad_Name"), ("namespace", "prod")])).is_err());
+        assert!(render(&template, &values(&[("name", "my.app"), ("namespace", "prod")])).is_err());
+        assert!(render(&template, &values(&[("name", &"a".repeat(64)), ("namespace", "prod")])).is_err());
+    }
+
+    #[test]
+    fn test_render_keeps_values_out_of_the_structure() {
+        let find = |kind: &str| builtin_templates().into_iter().find(|t| t.kind == kind).unwrap();
+
+        let secret = render(
+            &find("Secret"),
+            &values(&[("name", "db"), ("namespace", "prod"), ("value", r#"C:\temp\new "pw" # not a comment"#)]),
+        )
+        .unwrap();
+        let doc: serde_json::Value = serde_yaml::from_str(&secret).unwrap();
+        assert_eq!(doc["stringData"]["password"], r#"C:\temp\new "pw" # not a comment"#);
+
+        let job = render(
+            &find("Job"),
+            &values(&[("name", "job"), ("namespace", "prod"), ("image", "busybox # x: {y}"), ("command", "echo \"a\\b\"\nls")]),
+        )
+        .unwrap();
+        let doc: serde_json::Value = serde_yaml::from_str(&job).unwrap();
+        let container = &doc["spec"]["template"]["spec"]["containers"][0];
+        assert_eq!(container["image"], "busybox # x: {y}");
+        assert_eq!(container["command"], serde_json::json!(["sh", "-c", "echo \"a\\b\"\nls"]));
+
+        let service = render(&find("Service"), &values(&[("name", "api"), ("namespace", "prod"), ("port", "80: [x]")])).unwrap();
+        let doc: serde_json::Value = serde_yaml::from_str(&service).unwrap();
+        assert_eq!(doc["spec"]["ports"][0]["port"], "80: [x]");
+        assert_eq!(doc["spec"]["ports"][0]["targetPort"], 80);
+
+        let service = render(&find("Service"), &values(&[("name", "api"), ("namespace", "prod"), ("port", "80\nfoo: bar")])).unwrap();
+        let doc: serde_json::Value = serde_yaml::from_str(&service).unwrap();
+        assert_eq!(doc["spec"]["ports"][0]["port"], "80\nfoo: bar");
+        assert!(doc["spec"]["ports"][0].get("foo").is_none());
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+/// Dotted path of a mapping key, e.g. `spec.template`.
+pub fn join_key(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", parent, key)
+    }
+}
+
+/// Path of a sequence item, e.g. `spec.containers[0]`.
+pub fn join_index(parent: &str, index: usize) -> String {
+    format!("{}[{}]", parent, index)
+}
+
+struct Frame {
+    indent: usize,
+    path: String,
+    is_item: bool,
+    next_index: usize,
+}
+
+/// Split `key: value` into the (unquoted) key and the raw value.
+fn split_key(text: &str) -> Option<(String, &str)> {
+    if let Some(quote) = text.chars().next().filter(|c| *c == '"' || *c == '\'') {
+        let end = text[1..].find(quote)? + 1;
+        let rest = text[end + 1..].trim_start().strip_prefix(':')?;
+        return Some((text[1..end].to_string(), rest));
+    }
+    if text.starts_with(['{', '[']) {
+        return None;
+    }
+    match text.find(": ") {
+        Some(pos) => Some((text[..pos].trim_end().to_string(), &text[pos + 2..])),
+        None => text.strip_suffix(':').map(|k| (k.trim_end().to_string(), "")),
+    }
+}
+
+/// 1-based line/column of every mapping key and sequence item in a block-style YAML
+/// document, keyed by the same paths `join_key`/`join_index` build. Flow collections and
+/// multi-line plain scalars aren't tracked; `locate` falls back to the nearest ancestor.
+pub struct YamlPositions {
+    positions: HashMap<String, (usize, usize)>,
+}
+
+impl YamlPositions {
+    /// Index `yaml`, whose first line is line `line_offset + 1` of the editor buffer.
+    pub fn index(yaml: &str, line_offset: usize) -> Self {
+        let mut positions = HashMap::new();
+        let mut frames: Vec<Frame> = Vec::new();
+        let mut root_index = 0;
+        let mut block_scalar: Option<usize> = None;
+
+        for (n, raw) in yaml.lines().enumerate() {
+            let line_no = line_offset + n + 1;
+            let trimmed = raw.trim_start();
+            let mut indent = raw.len() - trimmed.len();
+
+            if let Some(block_indent) = block_scalar {
+                if trimmed.is_empty() || indent > block_indent {
+                    continue;
+                }
+                block_scalar = None;
+            }
+            if trimmed.is_empty() || trimmed.starts_with('#') || raw.starts_with("---") {
+                continue;
+            }
+
+            let mut text = trimmed.trim_end();
+            loop {
+                if text == "-" || text.starts_with("- ") {
+                    while let Some(top) = frames.last() {
+                        if top.indent > indent || (top.indent == indent && top.is_item) {
+                            frames.pop();
+                        } else {
+                            break;
+                        }
+                    }
+                    let path = match frames.last_mut() {
+                        Some(parent) => {
+                            parent.next_index += 1;
+                            join_index(&parent.path, parent.next_index - 1)
+                        }
+                        None => {
+                            root_index += 1;
+                            join_index("", root_index - 1)
+                        }
+                    };
+                    positions.insert(path.clone(), (line_no, indent + 1));
+                    frames.push(Frame { indent, path, is_item: true, next_index: 0 });
+
+                    let rest = text[1..].trim_start();
+                    if rest.is_empty() {
+                        break;
+                    }
+                    indent += text.len() - rest.len();
+                    text = rest;
+                    continue;
+                }
+
+                if let Some((key, value)) = split_key(text) {
+                    while frames.last().is_some_and(|top| top.indent >= indent) {
+                        frames.pop();
+                    }
+                    let path = join_key(frames.last().map(|f| f.path.as_str()).unwrap_or(""), &key);
+                    positions.insert(path.clone(), (line_no, indent + 1));
+
+                    let value = value.trim();
+                    if value.starts_with('|') || value.starts_with('>') {
+                        block_scalar = Some(indent);
+                    } else if value.is_empty() || value.starts_with('#') {
+                        frames.push(Frame { indent, path, is_item: false, next_index: 0 });
+                    }
+                }
+                break;
+            }
+        }
+        YamlPositions { positions }
+    }
+
+    /// Position of `path`, or of its closest indexed ancestor, or the document start.
+    pub fn locate(&self, path: &str, line_offset: usize) -> (usize, usize) {
+        let mut current = path;
+        loop {
+            if let Some(pos) = self.positions.get(current) {
+                return *pos;
+            }
+            match current.rfind(['.', '[']) {
+                Some(idx) => current = &current[..idx],
+                None => return (line_offset + 1, 1),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_block_yaml() {
+        let yaml = "\
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: web
+  annotations:
+    \"app.kubernetes.io/name\": web
+spec:
+  template:
+    spec:
+      containers:
+      - name: web
+        image: nginx
+        command:
+          - sh
+          - -c
+      - name: sidecar
+        args: |
+          not: a key
+        ports:
+        - containerPort: 80
+";
+        let pos = YamlPositions::index(yaml, 0);
+        assert_eq!(pos.locate("metadata.name", 0), (4, 3));
+        assert_eq!(pos.locate("metadata.annotations.app.kubernetes.io/name", 0), (6, 5));
+        assert_eq!(pos.locate("spec.template.spec.containers[0].image", 0), (12, 9));
+        assert_eq!(pos.locate("spec.template.spec.containers[0].command[1]", 0), (15, 11));
+        assert_eq!(pos.locate("spec.template.spec.containers[1]", 0), (16, 7));
+        assert_eq!(pos.locate("spec.template.spec.containers[1].ports[0].containerPort", 0), (20, 11));
+        // Unknown paths fall back to the nearest ancestor
+        assert_eq!(pos.locate("spec.template.spec.containers[1].resources.limits", 0), (16, 7));
+        assert_eq!(pos.locate("spec.template.spec.containers[1].args.not", 0), (17, 9));
+    }
+}
@@ -0,0 +1,48 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use super::common::{ConditionInfo, EventInfo};
+
+#[derive(Debug, Serialize, Clone)]
+pub struct HpaInfo {
+    pub name: String,
+    pub namespace: String,
+    pub target_kind: String,
+    pub target_name: String,
+    pub targets: String,
+    pub min_replicas: i32,
+    pub max_replicas: i32,
+    pub current_replicas: i32,
+    pub desired_replicas: i32,
+    pub age: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct HpaDetailInfo {
+    pub name: String,
+    pub namespace: String,
+    pub created: String,
+    pub labels: BTreeMap<String, String>,
+    pub annotations: BTreeMap<String, String>,
+    pub target_kind: String,
+    pub target_name: String,
+    pub target_api_version: String,
+    pub target_ready: String,
+    pub min_replicas: i32,
+    pub max_replicas: i32,
+    pub current_replicas: i32,
+    pub desired_replicas: i32,
+    pub last_scale_time: String,
+    pub metrics: Vec<HpaMetricInfo>,
+    pub conditions: Vec<ConditionInfo>,
+    pub events: Vec<EventInfo>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct HpaMetricInfo {
+    pub metric_type: String,
+    pub name: String,
+    pub target_type: String,
+    pub target_value: String,
+    pub current_value: String,
+}
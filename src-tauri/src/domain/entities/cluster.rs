@@ -1,4 +1,8 @@
 use serde::Serialize;
+use std::collections::BTreeMap;
+
+use super::common::{ConditionInfo, FinalizerInfo};
+use super::policy::ResourceQuotaInfo;
 
 #[derive(Debug, Serialize, Clone)]
 pub struct ContextInfo {
@@ -16,6 +20,37 @@ pub struct NamespaceInfo {
     pub age: String,
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct NamespaceResourceCount {
+    pub api_version: String,
+    pub kind: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct NamespaceRemainingResource {
+    pub api_version: String,
+    pub kind: String,
+    pub name: String,
+    pub deletion_timestamp: String,
+    pub finalizers: Vec<FinalizerInfo>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct NamespaceDetailInfo {
+    pub name: String,
+    pub status: String,
+    pub age: String,
+    pub deletion_timestamp: String,
+    pub labels: BTreeMap<String, String>,
+    pub annotations: BTreeMap<String, String>,
+    pub finalizers: Vec<String>,
+    pub resource_counts: Vec<NamespaceResourceCount>,
+    pub quotas: Vec<ResourceQuotaInfo>,
+    pub conditions: Vec<ConditionInfo>,
+    pub remaining: Vec<NamespaceRemainingResource>,
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct CRDInfo {
     pub name: String,
@@ -24,6 +59,25 @@ pub struct CRDInfo {
     pub kind: String,
     pub plural: String,
     pub scope: String,
+    pub versions: Vec<CRDVersionInfo>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CRDVersionInfo {
+    pub name: String,
+    pub served: bool,
+    pub storage: bool,
+    pub deprecated: bool,
+    pub printer_columns: Vec<CRDPrinterColumnInfo>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CRDPrinterColumnInfo {
+    pub name: String,
+    pub column_type: String,
+    pub json_path: String,
+    pub description: String,
+    pub priority: i32,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -31,6 +85,7 @@ pub struct CRDInstanceInfo {
     pub name: String,
     pub namespace: String,
     pub age: String,
+    pub columns: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Clone)]
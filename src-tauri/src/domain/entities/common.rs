@@ -1,12 +1,30 @@
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
+use super::networking::EndpointHealthInfo;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct GraphNode {
     pub id: String,
     pub label: String,
     pub node_type: String,
     pub status: String,
+    pub service: Option<ServiceNodeInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ServiceNodeInfo {
+    pub service_type: String,
+    pub endpoint_pods: Vec<String>,
+    pub endpoints: EndpointHealthInfo,
+    pub workloads: Vec<ServiceNodeWorkload>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ServiceNodeWorkload {
+    pub name: String,
+    pub ready: String,
+    pub pod_count: i32,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -51,6 +69,43 @@ pub struct GenericResourceListItem {
     pub status: String,
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct ApiResourceInfo {
+    pub group: String,
+    pub version: String,
+    pub kind: String,
+    pub plural: String,
+    pub singular: String,
+    pub short_names: Vec<String>,
+    pub namespaced: bool,
+    pub verbs: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct TableColumnInfo {
+    pub name: String,
+    pub column_type: String,
+    pub format: String,
+    pub description: String,
+    pub priority: i32,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct TableRowInfo {
+    pub name: String,
+    pub namespace: String,
+    pub cells: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ResourceTableInfo {
+    pub kind: String,
+    pub api_version: String,
+    pub namespaced: bool,
+    pub columns: Vec<TableColumnInfo>,
+    pub rows: Vec<TableRowInfo>,
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct GenericResourceDetailInfo {
     pub name: String,
@@ -65,3 +120,145 @@ pub struct GenericResourceDetailInfo {
     pub extra: BTreeMap<String, serde_json::Value>,
     pub events: Vec<EventInfo>,
 }
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct FieldConflict {
+    pub manager: String,
+    pub api_version: String,
+    pub field: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ApplyResult {
+    pub applied: bool,
+    pub forced: bool,
+    pub conflicts: Vec<FieldConflict>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ManifestApplyResult {
+    pub api_version: String,
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub success: bool,
+    pub error: String,
+    pub conflicts: Vec<FieldConflict>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SchemaIssue {
+    pub document: usize,
+    pub kind: String,
+    pub name: String,
+    pub path: String,
+    pub line: usize,
+    pub column: usize,
+    pub issue_type: String,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SchemaValidationResult {
+    pub valid: bool,
+    pub issues: Vec<SchemaIssue>,
+    pub unchecked: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DryRunResult {
+    pub yaml: String,
+    pub changed: bool,
+    pub diff: Vec<DiffLine>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ConditionInfo {
+    pub condition_type: String,
+    pub status: String,
+    pub reason: String,
+    pub message: String,
+    pub last_transition: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DiffLine {
+    pub op: String,
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ResourceRef {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub group: Option<String>,
+    pub version: Option<String>,
+    pub plural: Option<String>,
+    pub cluster_scoped: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct BulkItemResult {
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub success: bool,
+    pub error: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct MetadataEdit {
+    #[serde(default)]
+    pub set: BTreeMap<String, String>,
+    #[serde(default)]
+    pub remove: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct FinalizerInfo {
+    pub name: String,
+    pub controller: String,
+    pub hint: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct StuckResourceInfo {
+    pub api_version: String,
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub deletion_timestamp: String,
+    pub terminating_for: String,
+    pub terminating_seconds: i64,
+    pub finalizers: Vec<FinalizerInfo>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct FieldManagerInfo {
+    pub manager: String,
+    pub operation: String,
+    pub api_version: String,
+    pub subresource: String,
+    pub time: String,
+    pub age: String,
+    pub field_count: usize,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct FieldOwnerInfo {
+    pub path: String,
+    pub manager: String,
+    pub operation: String,
+    pub subresource: String,
+    pub time: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct FieldOwnershipInfo {
+    pub yaml: String,
+    pub managers: Vec<FieldManagerInfo>,
+    pub fields: Vec<FieldOwnerInfo>,
+}
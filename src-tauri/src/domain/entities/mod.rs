@@ -1,3 +1,4 @@
+pub mod autoscaling;
 pub mod cluster;
 pub mod common;
 pub mod config;
@@ -7,14 +8,19 @@ pub mod incident;
 pub mod minikube;
 pub mod networking;
 pub mod pod;
+pub mod policy;
+pub mod template;
 pub mod newrelic;
 pub mod workload;
 
 // Re-export all entities for convenience
+pub use autoscaling::*;
 pub use cluster::*;
 pub use common::*;
 pub use config::*;
 pub use gateway::*;
 pub use networking::*;
 pub use pod::*;
+pub use policy::*;
+pub use template::*;
 pub use workload::*;
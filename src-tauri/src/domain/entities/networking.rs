@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 use super::common::{EventInfo, OwnerRefInfo};
@@ -11,9 +11,81 @@ pub struct ServiceInfo {
     pub cluster_ip: String,
     pub external_ip: String,
     pub ports: String,
+    pub endpoints: EndpointHealthInfo,
     pub age: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct EndpointHealthInfo {
+    pub ready: i32,
+    pub serving: i32,
+    pub terminating: i32,
+    pub zones: Vec<ZoneEndpointInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ZoneEndpointInfo {
+    pub zone: String,
+    pub ready: i32,
+    pub serving: i32,
+    pub terminating: i32,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ServiceDetailInfo {
+    pub name: String,
+    pub namespace: String,
+    pub created: String,
+    pub labels: BTreeMap<String, String>,
+    pub annotations: BTreeMap<String, String>,
+    pub service_type: String,
+    pub cluster_ips: Vec<String>,
+    pub external_ips: Vec<String>,
+    pub load_balancer_ingress: Vec<String>,
+    pub external_name: Option<String>,
+    pub ports: Vec<ServicePortInfo>,
+    pub selector: BTreeMap<String, String>,
+    pub session_affinity: String,
+    pub session_affinity_timeout: Option<i32>,
+    pub external_traffic_policy: Option<String>,
+    pub internal_traffic_policy: Option<String>,
+    pub matching_pods: Vec<ServicePodInfo>,
+    pub endpoints: Vec<ServiceEndpointInfo>,
+    pub endpoint_health: EndpointHealthInfo,
+    pub diagnostics: Vec<String>,
+    pub events: Vec<EventInfo>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ServicePortInfo {
+    pub name: String,
+    pub protocol: String,
+    pub port: i32,
+    pub target_port: String,
+    pub node_port: Option<i32>,
+    pub app_protocol: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ServicePodInfo {
+    pub name: String,
+    pub phase: String,
+    pub ready: bool,
+    pub ip: String,
+    pub node: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ServiceEndpointInfo {
+    pub addresses: Vec<String>,
+    pub target: String,
+    pub node: String,
+    pub zone: String,
+    pub ready: bool,
+    pub serving: bool,
+    pub terminating: bool,
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct IngressInfo {
     pub name: String,
@@ -60,3 +132,56 @@ pub struct IngressTlsInfo {
     pub hosts: Vec<String>,
     pub secret_name: String,
 }
+
+#[derive(Debug, Serialize, Clone)]
+pub struct NetworkPolicyInfo {
+    pub name: String,
+    pub namespace: String,
+    pub pod_selector: String,
+    pub policy_types: Vec<String>,
+    pub ingress_rules: i32,
+    pub egress_rules: i32,
+    pub age: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct NetworkPolicyDetailInfo {
+    pub name: String,
+    pub namespace: String,
+    pub created: String,
+    pub labels: BTreeMap<String, String>,
+    pub annotations: BTreeMap<String, String>,
+    pub pod_selector: String,
+    pub policy_types: Vec<String>,
+    pub ingress: Vec<NetworkPolicyRuleInfo>,
+    pub egress: Vec<NetworkPolicyRuleInfo>,
+    pub selected_pods: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct NetworkPolicyRuleInfo {
+    pub peers: Vec<String>,
+    pub ports: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ReachabilityResult {
+    pub allowed: bool,
+    pub source: String,
+    pub destination: String,
+    pub port: i32,
+    pub protocol: String,
+    pub egress: PolicyVerdict,
+    pub ingress: PolicyVerdict,
+    pub explanation: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PolicyVerdict {
+    pub allowed: bool,
+    pub isolated: bool,
+    pub selecting_policies: Vec<String>,
+    pub matched_policy: Option<String>,
+    pub matched_rule: Option<i32>,
+    pub reason: String,
+}
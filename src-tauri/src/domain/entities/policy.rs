@@ -0,0 +1,102 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use super::common::{ConditionInfo, EventInfo};
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PdbInfo {
+    pub name: String,
+    pub namespace: String,
+    pub min_available: String,
+    pub max_unavailable: String,
+    pub allowed_disruptions: i32,
+    pub current_healthy: i32,
+    pub desired_healthy: i32,
+    pub expected_pods: i32,
+    pub age: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PdbDetailInfo {
+    pub name: String,
+    pub namespace: String,
+    pub created: String,
+    pub labels: BTreeMap<String, String>,
+    pub annotations: BTreeMap<String, String>,
+    pub selector: Vec<String>,
+    pub min_available: String,
+    pub max_unavailable: String,
+    pub unhealthy_pod_eviction_policy: String,
+    pub allowed_disruptions: i32,
+    pub current_healthy: i32,
+    pub desired_healthy: i32,
+    pub expected_pods: i32,
+    pub matching_pods: Vec<PdbPodInfo>,
+    pub conditions: Vec<ConditionInfo>,
+    pub events: Vec<EventInfo>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PdbPodInfo {
+    pub name: String,
+    pub phase: String,
+    pub ready: bool,
+    pub disrupted: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ResourceQuotaInfo {
+    pub name: String,
+    pub namespace: String,
+    pub resources: Vec<QuotaUsageInfo>,
+    pub age: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ResourceQuotaDetailInfo {
+    pub name: String,
+    pub namespace: String,
+    pub created: String,
+    pub labels: BTreeMap<String, String>,
+    pub annotations: BTreeMap<String, String>,
+    pub scopes: Vec<String>,
+    pub resources: Vec<QuotaUsageInfo>,
+    pub events: Vec<EventInfo>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct QuotaUsageInfo {
+    pub resource: String,
+    pub used: String,
+    pub hard: String,
+    pub percent: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct LimitRangeInfo {
+    pub name: String,
+    pub namespace: String,
+    pub limit_types: Vec<String>,
+    pub age: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct LimitRangeDetailInfo {
+    pub name: String,
+    pub namespace: String,
+    pub created: String,
+    pub labels: BTreeMap<String, String>,
+    pub annotations: BTreeMap<String, String>,
+    pub limits: Vec<LimitRangeItemInfo>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct LimitRangeItemInfo {
+    pub limit_type: String,
+    pub resource: String,
+    pub min: String,
+    pub max: String,
+    pub default_request: String,
+    pub default_limit: String,
+    pub max_limit_request_ratio: String,
+}
@@ -1,4 +1,8 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::common::{ConditionInfo, DiffLine, EventInfo};
+use super::pod::ProbeInfo;
 
 #[derive(Debug, Serialize, Clone)]
 pub struct DeploymentInfo {
@@ -8,6 +12,7 @@ pub struct DeploymentInfo {
     pub up_to_date: i32,
     pub available: i32,
     pub age: String,
+    pub blocked_by: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -27,6 +32,7 @@ pub struct StatefulSetInfo {
     pub namespace: String,
     pub ready: String,
     pub age: String,
+    pub blocked_by: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -69,3 +75,216 @@ pub struct CronJobInfo {
     pub last_schedule: String,
     pub age: String,
 }
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DeploymentDetailInfo {
+    pub name: String,
+    pub namespace: String,
+    pub created: String,
+    pub labels: BTreeMap<String, String>,
+    pub annotations: BTreeMap<String, String>,
+    pub selector: String,
+    pub replicas: i32,
+    pub updated_replicas: i32,
+    pub ready_replicas: i32,
+    pub available_replicas: i32,
+    pub unavailable_replicas: i32,
+    pub strategy_type: String,
+    pub max_surge: Option<String>,
+    pub max_unavailable: Option<String>,
+    pub min_ready_seconds: i32,
+    pub revision_history_limit: i32,
+    pub progress_deadline_seconds: i32,
+    pub paused: bool,
+    pub current_revision: String,
+    pub rollout_stuck: bool,
+    pub rollout_message: String,
+    pub conditions: Vec<ConditionInfo>,
+    pub replica_sets: Vec<DeploymentReplicaSetInfo>,
+    pub containers: Vec<WorkloadContainerInfo>,
+    pub events: Vec<EventInfo>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DeploymentReplicaSetInfo {
+    pub name: String,
+    pub revision: String,
+    pub replicas: i32,
+    pub ready_replicas: i32,
+    pub images: Vec<String>,
+    pub age: String,
+    pub current: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct WorkloadContainerInfo {
+    pub name: String,
+    pub image: String,
+    pub ports: Vec<String>,
+    pub requests_cpu: String,
+    pub requests_memory: String,
+    pub limits_cpu: String,
+    pub limits_memory: String,
+    pub liveness: Option<ProbeInfo>,
+    pub readiness: Option<ProbeInfo>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct RolloutUndoPreview {
+    pub deployment: String,
+    pub current_revision: String,
+    pub target_revision: String,
+    pub target_replica_set: String,
+    pub changed: bool,
+    pub diff: Vec<DiffLine>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct RolloutProgressEvent {
+    pub deployment: String,
+    pub revision: String,
+    pub new_replica_set: String,
+    pub replicas: i32,
+    pub updated_replicas: i32,
+    pub ready_replicas: i32,
+    pub available_replicas: i32,
+    pub phase: String,
+    pub message: String,
+    pub failure_reason: Option<String>,
+    pub done: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CronJobRunInfo {
+    pub name: String,
+    pub status: String,
+    pub reason: String,
+    pub start_time: String,
+    pub completion_time: String,
+    pub duration: String,
+    pub succeeded: i32,
+    pub failed: i32,
+    pub active: i32,
+    pub manual: bool,
+    pub age: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CronScheduleInfo {
+    pub schedule: String,
+    pub timezone: String,
+    pub suspended: bool,
+    pub next_runs: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct JobDetailInfo {
+    pub name: String,
+    pub namespace: String,
+    pub created: String,
+    pub labels: BTreeMap<String, String>,
+    pub annotations: BTreeMap<String, String>,
+    pub status: String,
+    pub reason: String,
+    pub completions: i32,
+    pub parallelism: i32,
+    pub completion_mode: String,
+    pub backoff_limit: i32,
+    pub active_deadline_seconds: Option<i64>,
+    pub succeeded: i32,
+    pub failed: i32,
+    pub active: i32,
+    pub start_time: String,
+    pub completion_time: String,
+    pub duration: String,
+    pub backoff: String,
+    pub conditions: Vec<ConditionInfo>,
+    pub attempts: Vec<JobAttemptInfo>,
+    pub containers: Vec<WorkloadContainerInfo>,
+    pub events: Vec<EventInfo>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct JobAttemptInfo {
+    pub pod_name: String,
+    pub phase: String,
+    pub node: String,
+    pub completion_index: Option<String>,
+    pub start_time: String,
+    pub age: String,
+    pub containers: Vec<AttemptContainerInfo>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct AttemptContainerInfo {
+    pub name: String,
+    pub state: String,
+    pub exit_code: Option<i32>,
+    pub reason: String,
+    pub message: String,
+    pub restart_count: i32,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct WorkloadEdit {
+    #[serde(default)]
+    pub containers: Vec<ContainerEdit>,
+    pub min_ready_seconds: Option<i32>,
+    pub revision_history_limit: Option<i32>,
+    pub progress_deadline_seconds: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ContainerEdit {
+    pub name: String,
+    pub image: Option<String>,
+    #[serde(default)]
+    pub set_env: Vec<EnvVarEdit>,
+    #[serde(default)]
+    pub remove_env: Vec<String>,
+    pub liveness: Option<ProbeEdit>,
+    pub readiness: Option<ProbeEdit>,
+    #[serde(default)]
+    pub remove_liveness: bool,
+    #[serde(default)]
+    pub remove_readiness: bool,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct EnvVarEdit {
+    pub name: String,
+    pub value: Option<String>,
+    pub secret_ref: Option<KeyRefEdit>,
+    pub config_map_ref: Option<KeyRefEdit>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct KeyRefEdit {
+    pub name: String,
+    pub key: String,
+    pub optional: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ProbeEdit {
+    pub probe_type: String,
+    pub path: Option<String>,
+    pub port: Option<String>,
+    pub scheme: Option<String>,
+    #[serde(default)]
+    pub command: Vec<String>,
+    pub initial_delay_seconds: Option<i32>,
+    pub timeout_seconds: Option<i32>,
+    pub period_seconds: Option<i32>,
+    pub success_threshold: Option<i32>,
+    pub failure_threshold: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct WorkloadEditPreview {
+    pub kind: String,
+    pub name: String,
+    pub patch: serde_json::Value,
+    pub changed: bool,
+    pub diff: Vec<DiffLine>,
+}
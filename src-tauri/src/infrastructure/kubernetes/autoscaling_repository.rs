@@ -0,0 +1,250 @@
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet, StatefulSet};
+use k8s_openapi::api::autoscaling::v2::{
+    HorizontalPodAutoscaler, HorizontalPodAutoscalerSpec, HorizontalPodAutoscalerStatus, MetricStatus,
+    MetricTarget, MetricValueStatus,
+};
+use kube::{api::ListParams, Api, Client};
+
+use crate::application::services::formatting::format_age;
+use crate::domain::entities::autoscaling::*;
+use crate::domain::entities::common::ConditionInfo;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::helpers::fetch_events_for;
+
+/// Format a metric target as `(target_type, value)`, e.g. `("Utilization", "80%")`.
+fn format_metric_target(target: &MetricTarget) -> (String, String) {
+    let value = if let Some(util) = target.average_utilization {
+        format!("{}%", util)
+    } else if let Some(avg) = &target.average_value {
+        avg.0.clone()
+    } else if let Some(val) = &target.value {
+        val.0.clone()
+    } else {
+        String::new()
+    };
+    (target.type_.clone(), value)
+}
+
+fn format_metric_value(current: &MetricValueStatus) -> String {
+    if let Some(util) = current.average_utilization {
+        format!("{}%", util)
+    } else if let Some(avg) = &current.average_value {
+        avg.0.clone()
+    } else if let Some(val) = &current.value {
+        val.0.clone()
+    } else {
+        "<unknown>".to_string()
+    }
+}
+
+/// Name and current value of a metric status entry, used to pair it with its spec entry.
+fn metric_status_entry(ms: &MetricStatus) -> Option<(String, String)> {
+    if let Some(r) = &ms.resource {
+        Some((r.name.clone(), format_metric_value(&r.current)))
+    } else if let Some(cr) = &ms.container_resource {
+        Some((format!("{}/{}", cr.container, cr.name), format_metric_value(&cr.current)))
+    } else if let Some(p) = &ms.pods {
+        Some((p.metric.name.clone(), format_metric_value(&p.current)))
+    } else if let Some(o) = &ms.object {
+        Some((o.metric.name.clone(), format_metric_value(&o.current)))
+    } else {
+        ms.external
+            .as_ref()
+            .map(|e| (e.metric.name.clone(), format_metric_value(&e.current)))
+    }
+}
+
+/// Pair each metric in the HPA spec with its current value from the status.
+fn build_metrics(
+    spec: &HorizontalPodAutoscalerSpec,
+    status: &HorizontalPodAutoscalerStatus,
+) -> Vec<HpaMetricInfo> {
+    let current_metrics = status.current_metrics.as_deref().unwrap_or_default();
+
+    spec.metrics
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|m| {
+            let (name, target) = if let Some(r) = &m.resource {
+                (r.name.clone(), &r.target)
+            } else if let Some(cr) = &m.container_resource {
+                (format!("{}/{}", cr.container, cr.name), &cr.target)
+            } else if let Some(p) = &m.pods {
+                (p.metric.name.clone(), &p.target)
+            } else if let Some(o) = &m.object {
+                (o.metric.name.clone(), &o.target)
+            } else if let Some(e) = &m.external {
+                (e.metric.name.clone(), &e.target)
+            } else {
+                return None;
+            };
+
+            let (target_type, target_value) = format_metric_target(target);
+            let current_value = current_metrics
+                .iter()
+                .filter(|ms| ms.type_ == m.type_)
+                .filter_map(metric_status_entry)
+                .find(|(n, _)| *n == name)
+                .map(|(_, v)| v)
+                .unwrap_or_else(|| "<unknown>".to_string());
+
+            Some(HpaMetricInfo {
+                metric_type: m.type_.clone(),
+                name,
+                target_type,
+                target_value,
+                current_value,
+            })
+        })
+        .collect()
+}
+
+/// Render metrics the way `kubectl get hpa` does: `cpu: 45%/80%, memory: 120Mi/256Mi`.
+fn format_targets(metrics: &[HpaMetricInfo]) -> String {
+    if metrics.is_empty() {
+        return "<none>".to_string();
+    }
+    metrics
+        .iter()
+        .map(|m| format!("{}: {}/{}", m.name, m.current_value, m.target_value))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub async fn list_hpas(client: &Client, namespace: &str) -> Result<Vec<HpaInfo>, DomainError> {
+    let api: Api<HorizontalPodAutoscaler> = Api::namespaced(client.clone(), namespace);
+    let list = api.list(&ListParams::default()).await?;
+
+    Ok(list
+        .items
+        .into_iter()
+        .map(|hpa| {
+            let meta = hpa.metadata;
+            let spec = hpa.spec.unwrap_or_default();
+            let status = hpa.status.unwrap_or_default();
+            let metrics = build_metrics(&spec, &status);
+
+            HpaInfo {
+                name: meta.name.unwrap_or_default(),
+                namespace: meta.namespace.unwrap_or_default(),
+                target_kind: spec.scale_target_ref.kind.clone(),
+                target_name: spec.scale_target_ref.name.clone(),
+                targets: format_targets(&metrics),
+                min_replicas: spec.min_replicas.unwrap_or(1),
+                max_replicas: spec.max_replicas,
+                current_replicas: status.current_replicas.unwrap_or(0),
+                desired_replicas: status.desired_replicas,
+                age: format_age(meta.creation_timestamp.as_ref()),
+            }
+        })
+        .collect())
+}
+
+/// Resolve "ready/desired" for the workload an HPA scales. Returns an empty string
+/// for kinds we don't know how to read or when the target no longer exists.
+async fn fetch_target_ready(client: &Client, namespace: &str, kind: &str, name: &str) -> String {
+    match kind {
+        "Deployment" => {
+            let api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+            match api.get(name).await {
+                Ok(dep) => {
+                    let desired = dep.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+                    let ready = dep.status.as_ref().and_then(|s| s.ready_replicas).unwrap_or(0);
+                    format!("{}/{}", ready, desired)
+                }
+                Err(_) => String::new(),
+            }
+        }
+        "StatefulSet" => {
+            let api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+            match api.get(name).await {
+                Ok(ss) => {
+                    let desired = ss.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+                    let ready = ss.status.as_ref().and_then(|s| s.ready_replicas).unwrap_or(0);
+                    format!("{}/{}", ready, desired)
+                }
+                Err(_) => String::new(),
+            }
+        }
+        "ReplicaSet" => {
+            let api: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+            match api.get(name).await {
+                Ok(rs) => {
+                    let desired = rs.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+                    let ready = rs.status.as_ref().and_then(|s| s.ready_replicas).unwrap_or(0);
+                    format!("{}/{}", ready, desired)
+                }
+                Err(_) => String::new(),
+            }
+        }
+        _ => String::new(),
+    }
+}
+
+pub async fn get_hpa_detail(
+    client: &Client,
+    namespace: &str,
+    hpa_name: &str,
+) -> Result<HpaDetailInfo, DomainError> {
+    let api: Api<HorizontalPodAutoscaler> = Api::namespaced(client.clone(), namespace);
+    let hpa = api.get(hpa_name).await?;
+
+    let meta = hpa.metadata;
+    let spec = hpa.spec.unwrap_or_default();
+    let status = hpa.status.unwrap_or_default();
+
+    let name = meta.name.clone().unwrap_or_default();
+    let ns = meta.namespace.clone().unwrap_or_default();
+
+    let created = meta
+        .creation_timestamp
+        .as_ref()
+        .map(|t| t.0.to_rfc3339())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let metrics = build_metrics(&spec, &status);
+
+    let conditions: Vec<ConditionInfo> = status
+        .conditions
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|c| ConditionInfo {
+            condition_type: c.type_,
+            status: c.status,
+            reason: c.reason.unwrap_or_default(),
+            message: c.message.unwrap_or_default(),
+            last_transition: format_age(c.last_transition_time.as_ref()),
+        })
+        .collect();
+
+    let target = &spec.scale_target_ref;
+    let target_ready = fetch_target_ready(client, namespace, &target.kind, &target.name).await;
+
+    let events = fetch_events_for(client, namespace, &name, "HorizontalPodAutoscaler").await;
+
+    Ok(HpaDetailInfo {
+        name,
+        namespace: ns,
+        created,
+        labels: meta.labels.unwrap_or_default(),
+        annotations: meta.annotations.unwrap_or_default(),
+        target_kind: target.kind.clone(),
+        target_name: target.name.clone(),
+        target_api_version: target.api_version.clone().unwrap_or_default(),
+        target_ready,
+        min_replicas: spec.min_replicas.unwrap_or(1),
+        max_replicas: spec.max_replicas,
+        current_replicas: status.current_replicas.unwrap_or(0),
+        desired_replicas: status.desired_replicas,
+        last_scale_time: status
+            .last_scale_time
+            .as_ref()
+            .map(|t| format_age(Some(t)))
+            .unwrap_or_else(|| "-".to_string()),
+        metrics,
+        conditions,
+        events,
+    })
+}
@@ -0,0 +1,396 @@
+use std::collections::BTreeMap;
+
+use chrono::Utc;
+use k8s_openapi::api::batch::v1::{CronJob, Job, JobStatus};
+use k8s_openapi::api::core::v1::{ContainerStatus, Pod};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+use kube::{
+    api::{ListParams, Patch, PatchParams, PostParams},
+    Api, Client,
+};
+
+use crate::application::services::cron::CronSchedule;
+use crate::application::services::formatting::format_age;
+use crate::domain::entities::common::ConditionInfo;
+use crate::domain::entities::workload::*;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::deployment_repository::build_template_containers;
+use crate::infrastructure::kubernetes::helpers::fetch_events_for;
+
+const INSTANTIATE_ANNOTATION: &str = "cronjob.kubernetes.io/instantiate";
+const COMPLETION_INDEX_ANNOTATION: &str = "batch.kubernetes.io/job-completion-index";
+
+/// Labels the Job controller stamps on a Job and its pod template. A copy must drop them
+/// so the API server generates fresh ones for the new Job's uid.
+const CONTROLLER_LABELS: [&str; 4] = [
+    "controller-uid",
+    "batch.kubernetes.io/controller-uid",
+    "job-name",
+    "batch.kubernetes.io/job-name",
+];
+
+/// Outcome of a Job as `(status, reason)`, derived from its conditions first and counters second.
+pub fn job_outcome(status: &JobStatus) -> (String, String) {
+    let conditions = status.conditions.as_deref().unwrap_or_default();
+    let active = |t: &str| conditions.iter().find(|c| c.type_ == t && c.status == "True");
+
+    if let Some(c) = active("Failed") {
+        return ("Failed".to_string(), c.reason.clone().unwrap_or_default());
+    }
+    if active("Complete").is_some() {
+        return ("Complete".to_string(), String::new());
+    }
+    if let Some(c) = active("Suspended") {
+        return ("Suspended".to_string(), c.reason.clone().unwrap_or_default());
+    }
+    if status.active.unwrap_or(0) > 0 {
+        return ("Running".to_string(), String::new());
+    }
+    ("Pending".to_string(), String::new())
+}
+
+fn job_duration(status: &JobStatus) -> String {
+    match (status.start_time.as_ref(), status.completion_time.as_ref()) {
+        (Some(start), Some(end)) => format!("{}s", end.0.signed_duration_since(start.0).num_seconds()),
+        (Some(start), None) if status.active.unwrap_or(0) > 0 => {
+            format!("{}s", Utc::now().signed_duration_since(start.0).num_seconds())
+        }
+        _ => "-".to_string(),
+    }
+}
+
+/// Create a Job from the CronJob's template right now, like `kubectl create job --from=cronjob/<name>`.
+pub async fn trigger_cronjob(client: &Client, namespace: &str, cronjob_name: &str) -> Result<String, DomainError> {
+    let cj_api: Api<CronJob> = Api::namespaced(client.clone(), namespace);
+    let cj = cj_api.get(cronjob_name).await?;
+    let template = cj.spec.as_ref().map(|s| s.job_template.clone()).unwrap_or_default();
+    let template_meta = template.metadata.unwrap_or_default();
+
+    // Job names end up in the `job-name` pod label, which is capped at 63 characters
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let prefix: String = cronjob_name.chars().take(63 - "-manual-".len() - 5).collect();
+    let job_name = format!("{}-manual-{}", prefix, &suffix[..5]);
+
+    let mut annotations = template_meta.annotations.unwrap_or_default();
+    annotations.insert(INSTANTIATE_ANNOTATION.to_string(), "manual".to_string());
+
+    let job = Job {
+        metadata: ObjectMeta {
+            name: Some(job_name.clone()),
+            namespace: Some(namespace.to_string()),
+            labels: template_meta.labels,
+            annotations: Some(annotations),
+            owner_references: Some(vec![OwnerReference {
+                api_version: "batch/v1".to_string(),
+                kind: "CronJob".to_string(),
+                name: cronjob_name.to_string(),
+                uid: cj.metadata.uid.clone().unwrap_or_default(),
+                controller: Some(true),
+                block_owner_deletion: None,
+            }]),
+            ..Default::default()
+        },
+        spec: template.spec,
+        status: None,
+    };
+
+    let job_api: Api<Job> = Api::namespaced(client.clone(), namespace);
+    job_api.create(&PostParams::default(), &job).await?;
+    Ok(job_name)
+}
+
+pub async fn set_cronjob_suspend(
+    client: &Client,
+    namespace: &str,
+    cronjob_name: &str,
+    suspend: bool,
+) -> Result<(), DomainError> {
+    let api: Api<CronJob> = Api::namespaced(client.clone(), namespace);
+    let patch = serde_json::json!({
+        "spec": { "suspend": suspend }
+    });
+    api.patch(cronjob_name, &PatchParams::apply("k0"), &Patch::Merge(&patch))
+        .await?;
+    Ok(())
+}
+
+/// Jobs owned by the CronJob, newest first, with their outcome and duration.
+pub async fn list_cronjob_runs(
+    client: &Client,
+    namespace: &str,
+    cronjob_name: &str,
+) -> Result<Vec<CronJobRunInfo>, DomainError> {
+    let cj_api: Api<CronJob> = Api::namespaced(client.clone(), namespace);
+    let cj = cj_api.get(cronjob_name).await?;
+    let uid = cj.metadata.uid.unwrap_or_default();
+
+    let job_api: Api<Job> = Api::namespaced(client.clone(), namespace);
+    let mut jobs: Vec<Job> = job_api
+        .list(&ListParams::default())
+        .await?
+        .items
+        .into_iter()
+        .filter(|j| {
+            j.metadata
+                .owner_references
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .any(|r| r.kind == "CronJob" && r.uid == uid)
+        })
+        .collect();
+    jobs.sort_by(|a, b| b.metadata.creation_timestamp.cmp(&a.metadata.creation_timestamp));
+
+    Ok(jobs
+        .into_iter()
+        .map(|job| {
+            let meta = job.metadata;
+            let status = job.status.unwrap_or_default();
+            let (outcome, reason) = job_outcome(&status);
+            let annotations: BTreeMap<String, String> = meta.annotations.unwrap_or_default();
+
+            CronJobRunInfo {
+                name: meta.name.unwrap_or_default(),
+                status: outcome,
+                reason,
+                start_time: status.start_time.as_ref().map(|t| t.0.to_rfc3339()).unwrap_or_default(),
+                completion_time: status.completion_time.as_ref().map(|t| t.0.to_rfc3339()).unwrap_or_default(),
+                duration: job_duration(&status),
+                succeeded: status.succeeded.unwrap_or(0),
+                failed: status.failed.unwrap_or(0),
+                active: status.active.unwrap_or(0),
+                manual: annotations.get(INSTANTIATE_ANNOTATION).map(|v| v == "manual").unwrap_or(false),
+                age: format_age(meta.creation_timestamp.as_ref()),
+            }
+        })
+        .collect())
+}
+
+/// Next `count` scheduled runs. The controller evaluates the schedule in `spec.timeZone`,
+/// or in its own local time (UTC on virtually every cluster) when unset.
+pub async fn get_cronjob_schedule(
+    client: &Client,
+    namespace: &str,
+    cronjob_name: &str,
+    count: usize,
+) -> Result<CronScheduleInfo, DomainError> {
+    let api: Api<CronJob> = Api::namespaced(client.clone(), namespace);
+    let spec = api.get(cronjob_name).await?.spec.unwrap_or_default();
+
+    let schedule = CronSchedule::parse(&spec.schedule)
+        .map_err(|e| DomainError::Configuration(format!("Invalid schedule \"{}\": {}", spec.schedule, e)))?;
+    let timezone = spec.time_zone.clone().unwrap_or_else(|| "UTC".to_string());
+    let tz: chrono_tz::Tz = timezone
+        .parse()
+        .map_err(|e| DomainError::Configuration(format!("Invalid timeZone \"{}\": {}", timezone, e)))?;
+
+    let next_runs = schedule
+        .next_runs(&Utc::now().with_timezone(&tz), count)
+        .iter()
+        .map(|t| t.to_rfc3339())
+        .collect();
+
+    Ok(CronScheduleInfo {
+        schedule: spec.schedule,
+        timezone,
+        suspended: spec.suspend.unwrap_or(false),
+        next_runs,
+    })
+}
+
+/// Create a fresh copy of a Job under a new name. Selector and controller-generated labels are
+/// dropped so the API server assigns new ones; owner references are dropped so a copy of a
+/// CronJob's Job isn't adopted into its history.
+pub async fn rerun_job(client: &Client, namespace: &str, job_name: &str) -> Result<String, DomainError> {
+    let api: Api<Job> = Api::namespaced(client.clone(), namespace);
+    let job = api.get(job_name).await?;
+
+    let base = job_name.split("-rerun-").next().unwrap_or(job_name);
+    // Job names end up in the `job-name` pod label, which is capped at 63 characters
+    let prefix: String = base.chars().take(63 - "-rerun-".len() - 5).collect();
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let new_name = format!("{}-rerun-{}", prefix, &suffix[..5]);
+
+    let strip = |labels: Option<BTreeMap<String, String>>| {
+        labels.map(|mut l| {
+            for key in CONTROLLER_LABELS {
+                l.remove(key);
+            }
+            l
+        })
+    };
+
+    let mut spec = job.spec.unwrap_or_default();
+    spec.selector = None;
+    spec.manual_selector = None;
+    if let Some(meta) = spec.template.metadata.as_mut() {
+        meta.labels = strip(meta.labels.take());
+    }
+
+    let new_job = Job {
+        metadata: ObjectMeta {
+            name: Some(new_name.clone()),
+            namespace: Some(namespace.to_string()),
+            labels: strip(job.metadata.labels),
+            annotations: job.metadata.annotations.map(|mut a| {
+                a.remove(INSTANTIATE_ANNOTATION);
+                a.remove("kubectl.kubernetes.io/last-applied-configuration");
+                a
+            }),
+            ..Default::default()
+        },
+        spec: Some(spec),
+        status: None,
+    };
+
+    api.create(&PostParams::default(), &new_job).await?;
+    Ok(new_name)
+}
+
+fn attempt_container(cs: &ContainerStatus) -> AttemptContainerInfo {
+    let state = cs.state.clone().unwrap_or_default();
+    // Report the last termination when the container has already been restarted in place
+    let terminated = state
+        .terminated
+        .clone()
+        .or_else(|| cs.last_state.as_ref().and_then(|s| s.terminated.clone()));
+
+    let (label, reason, message) = if let Some(w) = &state.waiting {
+        ("Waiting", w.reason.clone().unwrap_or_default(), w.message.clone().unwrap_or_default())
+    } else if state.running.is_some() {
+        ("Running", String::new(), String::new())
+    } else if let Some(t) = &state.terminated {
+        ("Terminated", t.reason.clone().unwrap_or_default(), t.message.clone().unwrap_or_default())
+    } else {
+        ("Unknown", String::new(), String::new())
+    };
+
+    AttemptContainerInfo {
+        name: cs.name.clone(),
+        state: label.to_string(),
+        exit_code: terminated.as_ref().map(|t| t.exit_code),
+        reason: if reason.is_empty() {
+            terminated.as_ref().and_then(|t| t.reason.clone()).unwrap_or_default()
+        } else {
+            reason
+        },
+        message,
+        restart_count: cs.restart_count,
+    }
+}
+
+/// Describe where the Job is in its retry cycle. The controller waits 10s, 20s, 40s, ...
+/// (capped at 6 minutes) before recreating a failed pod.
+fn describe_backoff(outcome: &str, failed: i32, active: i32, backoff_limit: i32) -> String {
+    match outcome {
+        "Complete" => return "Completed".to_string(),
+        "Failed" => return format!("Gave up after {} failed attempt(s) (backoffLimit {})", failed, backoff_limit),
+        _ => {}
+    }
+    if failed == 0 {
+        return "No failures".to_string();
+    }
+    let retries_left = (backoff_limit - failed + 1).max(0);
+    if active > 0 {
+        return format!("Retrying: {} failure(s), {} retries left", failed, retries_left);
+    }
+    let delay = (10u64 << (failed - 1).min(6) as u32).min(360);
+    format!(
+        "Backing off: {} failure(s), next attempt in up to {}s, {} retries left",
+        failed, delay, retries_left
+    )
+}
+
+pub async fn get_job_detail(client: &Client, namespace: &str, job_name: &str) -> Result<JobDetailInfo, DomainError> {
+    let api: Api<Job> = Api::namespaced(client.clone(), namespace);
+    let job = api.get(job_name).await?;
+
+    let meta = job.metadata;
+    let spec = job.spec.unwrap_or_default();
+    let status = job.status.unwrap_or_default();
+
+    let name = meta.name.clone().unwrap_or_default();
+    let created = meta
+        .creation_timestamp
+        .as_ref()
+        .map(|t| t.0.to_rfc3339())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let (outcome, reason) = job_outcome(&status);
+    let failed = status.failed.unwrap_or(0);
+    let active = status.active.unwrap_or(0);
+    let backoff_limit = spec.backoff_limit.unwrap_or(6);
+
+    let uid = meta.uid.clone().unwrap_or_default();
+    let pod_api: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let mut pods = pod_api
+        .list(&ListParams::default().labels(&format!("controller-uid={}", uid)))
+        .await?
+        .items;
+    pods.sort_by(|a, b| a.metadata.creation_timestamp.cmp(&b.metadata.creation_timestamp));
+
+    let attempts = pods
+        .into_iter()
+        .map(|p| {
+            let pod_status = p.status.unwrap_or_default();
+            let mut statuses = pod_status.init_container_statuses.unwrap_or_default();
+            statuses.extend(pod_status.container_statuses.unwrap_or_default());
+            JobAttemptInfo {
+                pod_name: p.metadata.name.unwrap_or_default(),
+                phase: pod_status.phase.unwrap_or_else(|| "Unknown".to_string()),
+                node: p.spec.and_then(|s| s.node_name).unwrap_or_default(),
+                completion_index: p
+                    .metadata
+                    .annotations
+                    .as_ref()
+                    .and_then(|a| a.get(COMPLETION_INDEX_ANNOTATION))
+                    .cloned(),
+                start_time: pod_status.start_time.as_ref().map(|t| t.0.to_rfc3339()).unwrap_or_default(),
+                age: format_age(p.metadata.creation_timestamp.as_ref()),
+                containers: statuses.iter().map(attempt_container).collect(),
+            }
+        })
+        .collect();
+
+    let conditions = status
+        .conditions
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|c| ConditionInfo {
+            condition_type: c.type_,
+            status: c.status,
+            reason: c.reason.unwrap_or_default(),
+            message: c.message.unwrap_or_default(),
+            last_transition: format_age(c.last_transition_time.as_ref()),
+        })
+        .collect();
+
+    let events = fetch_events_for(client, namespace, &name, "Job").await;
+
+    Ok(JobDetailInfo {
+        name,
+        namespace: meta.namespace.unwrap_or_default(),
+        created,
+        labels: meta.labels.unwrap_or_default(),
+        annotations: meta.annotations.unwrap_or_default(),
+        backoff: describe_backoff(&outcome, failed, active, backoff_limit),
+        status: outcome,
+        reason,
+        completions: spec.completions.unwrap_or(1),
+        parallelism: spec.parallelism.unwrap_or(1),
+        completion_mode: spec.completion_mode.clone().unwrap_or_else(|| "NonIndexed".to_string()),
+        backoff_limit,
+        active_deadline_seconds: spec.active_deadline_seconds,
+        succeeded: status.succeeded.unwrap_or(0),
+        failed,
+        active,
+        start_time: status.start_time.as_ref().map(|t| t.0.to_rfc3339()).unwrap_or_default(),
+        completion_time: status.completion_time.as_ref().map(|t| t.0.to_rfc3339()).unwrap_or_default(),
+        duration: job_duration(&status),
+        conditions,
+        attempts,
+        containers: build_template_containers(spec.template.spec.as_ref()),
+        events,
+    })
+}
@@ -0,0 +1,177 @@
+use std::future::Future;
+
+use futures::{stream, StreamExt};
+use k8s_openapi::api::core::v1::Pod;
+use kube::{
+    api::{ListParams, Patch, PatchParams},
+    discovery::ApiResource,
+    Api, Client, ResourceExt,
+};
+use serde_json::Value;
+
+use crate::domain::entities::common::BulkItemResult;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::helpers::build_dynamic_api;
+use crate::infrastructure::kubernetes::workload_repository;
+
+/// Maximum number of API requests a bulk operation keeps in flight.
+pub const BULK_CONCURRENCY: usize = 8;
+
+/// One object a bulk operation acts on, with its resource type already resolved.
+#[derive(Clone)]
+pub struct BulkTarget {
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub ar: ApiResource,
+    pub cluster_scoped: bool,
+}
+
+pub fn item_result(kind: &str, name: &str, namespace: &str, outcome: Result<(), DomainError>) -> BulkItemResult {
+    BulkItemResult {
+        kind: kind.to_string(),
+        name: name.to_string(),
+        namespace: namespace.to_string(),
+        success: outcome.is_ok(),
+        error: outcome.err().map(|e| e.to_string()).unwrap_or_default(),
+    }
+}
+
+/// Run `op` over `items` with at most `BULK_CONCURRENCY` in flight, keeping input order.
+pub async fn run_bulk<T, F, Fut>(items: Vec<T>, op: F) -> Vec<BulkItemResult>
+where
+    F: Fn(T) -> Fut,
+    Fut: Future<Output = BulkItemResult>,
+{
+    stream::iter(items).map(op).buffered(BULK_CONCURRENCY).collect().await
+}
+
+fn selector_params(label_selector: Option<&str>, field_selector: Option<&str>) -> ListParams {
+    let mut lp = ListParams::default();
+    if let Some(labels) = label_selector.filter(|s| !s.is_empty()) {
+        lp = lp.labels(labels);
+    }
+    if let Some(fields) = field_selector.filter(|s| !s.is_empty()) {
+        lp = lp.fields(fields);
+    }
+    lp
+}
+
+/// Objects of one resource type matching the selectors, in `namespace` or, when `None`,
+/// across all namespaces.
+pub async fn select_targets(
+    client: &Client,
+    namespace: Option<&str>,
+    ar: &ApiResource,
+    cluster_scoped: bool,
+    label_selector: Option<&str>,
+    field_selector: Option<&str>,
+) -> Result<Vec<BulkTarget>, DomainError> {
+    let api = match namespace {
+        Some(ns) => build_dynamic_api(client, ns, ar, cluster_scoped),
+        None => build_dynamic_api(client, "", ar, true),
+    };
+    let list = api.list(&selector_params(label_selector, field_selector)).await?;
+    Ok(list
+        .items
+        .iter()
+        .map(|obj| BulkTarget {
+            kind: ar.kind.clone(),
+            name: obj.name_any(),
+            namespace: obj.namespace().unwrap_or_default(),
+            ar: ar.clone(),
+            cluster_scoped,
+        })
+        .collect())
+}
+
+/// Pods matching the selectors and, if given, a `status.reason` such as "Evicted",
+/// as (namespace, name) pairs.
+pub async fn select_pods(
+    client: &Client,
+    namespace: Option<&str>,
+    label_selector: Option<&str>,
+    field_selector: Option<&str>,
+    reason: Option<&str>,
+) -> Result<Vec<(String, String)>, DomainError> {
+    let api: Api<Pod> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+    let list = api.list(&selector_params(label_selector, field_selector)).await?;
+    Ok(list
+        .items
+        .iter()
+        .filter(|pod| {
+            reason.is_none_or(|r| pod.status.as_ref().and_then(|s| s.reason.as_deref()) == Some(r))
+        })
+        .map(|pod| (pod.namespace().unwrap_or_default(), pod.name_any()))
+        .collect())
+}
+
+pub async fn delete_resources(client: &Client, targets: Vec<BulkTarget>) -> Vec<BulkItemResult> {
+    run_bulk(targets, |t| async move {
+        let api = build_dynamic_api(client, &t.namespace, &t.ar, t.cluster_scoped);
+        let outcome = api.delete(&t.name, &Default::default()).await.map(|_| ()).map_err(DomainError::from);
+        item_result(&t.kind, &t.name, &t.namespace, outcome)
+    })
+    .await
+}
+
+pub async fn patch_resources(client: &Client, targets: Vec<BulkTarget>, patch: &Patch<Value>) -> Vec<BulkItemResult> {
+    run_bulk(targets, |t| async move {
+        let api = build_dynamic_api(client, &t.namespace, &t.ar, t.cluster_scoped);
+        let outcome = api
+            .patch(&t.name, &PatchParams::default(), patch)
+            .await
+            .map(|_| ())
+            .map_err(DomainError::from);
+        item_result(&t.kind, &t.name, &t.namespace, outcome)
+    })
+    .await
+}
+
+pub async fn delete_pods(client: &Client, pods: Vec<(String, String)>) -> Vec<BulkItemResult> {
+    run_bulk(pods, |(namespace, name)| async move {
+        let api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+        let outcome = api.delete(&name, &Default::default()).await.map(|_| ()).map_err(DomainError::from);
+        item_result("Pod", &name, &namespace, outcome)
+    })
+    .await
+}
+
+/// Rolling-restart each (namespace, kind, name) workload.
+pub async fn restart_workloads(client: &Client, workloads: Vec<(String, String, String)>) -> Vec<BulkItemResult> {
+    run_bulk(workloads, |(namespace, kind, name)| async move {
+        let outcome = workload_repository::restart_workload(client, &namespace, &kind, &name).await;
+        item_result(&kind, &name, &namespace, outcome)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_bulk_keeps_order_and_reports_failures() {
+        let items: Vec<u64> = (0..20).collect();
+        let results = run_bulk(items, |i| async move {
+            // Later items finish first
+            tokio::time::sleep(std::time::Duration::from_millis(20 - i)).await;
+            let outcome = if i % 5 == 0 {
+                Err(DomainError::NotFound(format!("item {}", i)))
+            } else {
+                Ok(())
+            };
+            item_result("Pod", &i.to_string(), "default", outcome)
+        })
+        .await;
+
+        let names: Vec<String> = results.iter().map(|r| r.name.clone()).collect();
+        assert_eq!(names, (0..20).map(|i| i.to_string()).collect::<Vec<_>>());
+        assert_eq!(results.iter().filter(|r| !r.success).count(), 4);
+        assert!(results[5].error.contains("item 5"));
+        assert!(results[6].error.is_empty());
+    }
+}
@@ -6,12 +6,16 @@ use tokio::sync::Mutex;
 
 use crate::application::services::config_db::ConfigDB;
 use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::discovery_service::DiscoveryService;
+use crate::infrastructure::kubernetes::openapi_service::OpenApiService;
 
 pub struct ClientManager {
     pub clients: Arc<Mutex<HashMap<String, Client>>>,
     pub active_context: Arc<Mutex<Option<String>>>,
     pub active_namespace: Arc<Mutex<Option<String>>>,
     pub config_db: Arc<ConfigDB>,
+    pub discovery: DiscoveryService,
+    pub openapi: OpenApiService,
 }
 
 impl ClientManager {
@@ -21,6 +25,8 @@ impl ClientManager {
             active_context: Arc::new(Mutex::new(None)),
             active_namespace: Arc::new(Mutex::new(None)),
             config_db,
+            discovery: DiscoveryService::new(),
+            openapi: OpenApiService::new(),
         }
     }
 
@@ -58,6 +64,12 @@ impl ClientManager {
     /// Returns only the client — use for cluster-scoped or context-only operations
     /// (does NOT require an active namespace, safe to call before namespace is selected).
     pub async fn get_client_for_context(&self) -> Result<Client, DomainError> {
+        let (client, _) = self.get_client_and_context().await?;
+        Ok(client)
+    }
+
+    /// Returns `(client, context_name)` — like `get_client_for_context`, for per-context caches.
+    pub async fn get_client_and_context(&self) -> Result<(Client, String), DomainError> {
         let ctx = self.active_context.lock().await
             .as_ref()
             .ok_or(DomainError::NoActiveContext)?
@@ -68,7 +80,7 @@ impl ClientManager {
             .ok_or_else(|| DomainError::Configuration("Client not found".to_string()))?
             .clone();
 
-        Ok(client)
+        Ok((client, ctx))
     }
 
     pub async fn set_context(&self, name: &str) -> Result<(), DomainError> {
@@ -77,6 +89,9 @@ impl ClientManager {
 
         let mut clients = self.clients.lock().await;
         clients.insert(name.to_string(), client);
+        // The kubeconfig may now point this context at a different cluster
+        self.discovery.invalidate(name).await;
+        self.openapi.invalidate(name).await;
 
         let mut active = self.active_context.lock().await;
         *active = Some(name.to_string());
@@ -1,11 +1,29 @@
+use futures::{stream, StreamExt};
 use k8s_openapi::api::core::v1::Namespace;
-use kube::{api::ListParams, config::Kubeconfig, Api, Client};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::{
+    api::{ListParams, PostParams},
+    config::Kubeconfig,
+    Api, Client, ResourceExt,
+};
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 
 use crate::application::services::config_db::ConfigDB;
+use crate::application::services::finalizers::describe_finalizer;
 use crate::application::services::formatting::format_age;
-use crate::domain::entities::{ContextInfo, NamespaceInfo};
+use crate::application::services::labels;
+use crate::domain::entities::{
+    ApiResourceInfo, ConditionInfo, ContextInfo, NamespaceDetailInfo, NamespaceInfo, NamespaceRemainingResource,
+    NamespaceResourceCount,
+};
 use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::bulk_repository::BULK_CONCURRENCY;
+use crate::infrastructure::kubernetes::helpers::{build_api_resource, build_dynamic_api};
+use crate::infrastructure::kubernetes::policy_repository;
+
+/// Namespaces the cluster depends on; k0 refuses to delete them.
+const PROTECTED_NAMESPACES: [&str; 4] = ["default", "kube-system", "kube-public", "kube-node-lease"];
 
 fn read_kubeconfigs_from_paths(extra_paths: &[String]) -> Vec<Kubeconfig> {
     let mut configs = Vec::new();
@@ -98,20 +116,172 @@ pub async fn list_namespaces(client: &Client) -> Result<Vec<NamespaceInfo>, Doma
     let api: Api<Namespace> = Api::all(client.clone());
     let list = api.list(&ListParams::default()).await?;
 
-    Ok(list
-        .items
-        .into_iter()
-        .map(|ns| {
-            let meta = ns.metadata;
-            let status = ns
-                .status
-                .and_then(|s| s.phase)
-                .unwrap_or_else(|| "Unknown".to_string());
-            NamespaceInfo {
-                name: meta.name.unwrap_or_default(),
-                status,
-                age: format_age(meta.creation_timestamp.as_ref()),
+    Ok(list.items.iter().map(namespace_info).collect())
+}
+
+fn namespace_info(ns: &Namespace) -> NamespaceInfo {
+    NamespaceInfo {
+        name: ns.name_any(),
+        status: ns
+            .status
+            .as_ref()
+            .and_then(|s| s.phase.clone())
+            .unwrap_or_else(|| "Unknown".to_string()),
+        age: format_age(ns.metadata.creation_timestamp.as_ref()),
+    }
+}
+
+pub async fn create_namespace(
+    client: &Client,
+    name: &str,
+    ns_labels: &BTreeMap<String, String>,
+) -> Result<NamespaceInfo, DomainError> {
+    if !labels::is_dns_label(name) {
+        return Err(DomainError::Configuration(format!(
+            "Namespace name \"{}\" must be at most 63 lowercase letters, digits or '-', starting and ending with an alphanumeric",
+            name
+        )));
+    }
+    let mut errors = Vec::new();
+    for (key, value) in ns_labels {
+        if let Err(e) = labels::validate_key(key).and_then(|_| labels::validate_label_value(key, value)) {
+            errors.push(e);
+        }
+    }
+    if !errors.is_empty() {
+        return Err(DomainError::Configuration(errors.join("; ")));
+    }
+
+    let api: Api<Namespace> = Api::all(client.clone());
+    let ns = Namespace {
+        metadata: ObjectMeta {
+            name: Some(name.to_string()),
+            labels: (!ns_labels.is_empty()).then(|| ns_labels.clone()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let pp = PostParams {
+        field_manager: Some("k0".to_string()),
+        ..Default::default()
+    };
+    let created = api.create(&pp, &ns).await?;
+    Ok(namespace_info(&created))
+}
+
+pub async fn delete_namespace(client: &Client, name: &str) -> Result<(), DomainError> {
+    if PROTECTED_NAMESPACES.contains(&name) {
+        return Err(DomainError::Configuration(format!(
+            "Namespace {} is required by the cluster and can't be deleted",
+            name
+        )));
+    }
+    let api: Api<Namespace> = Api::all(client.clone());
+    api.delete(name, &Default::default()).await?;
+    Ok(())
+}
+
+/// Namespace detail: labels, quotas, conditions and the number of objects of each
+/// namespaced type in `resources`. For a Terminating namespace every object still
+/// present is listed along with the finalizers holding it.
+pub async fn get_namespace_detail(
+    client: &Client,
+    name: &str,
+    resources: &[ApiResourceInfo],
+) -> Result<NamespaceDetailInfo, DomainError> {
+    let api: Api<Namespace> = Api::all(client.clone());
+    let ns = api.get(name).await?;
+    let info = namespace_info(&ns);
+    let terminating = ns.metadata.deletion_timestamp.is_some();
+
+    // The same kind can be served by several groups (e.g. core and events.k8s.io Events);
+    // count it once. Metrics are views of pods and nodes rather than objects.
+    let mut seen = HashSet::new();
+    let types: Vec<&ApiResourceInfo> = resources
+        .iter()
+        .filter(|r| r.namespaced && r.verbs.iter().any(|v| v == "list") && r.group != "metrics.k8s.io")
+        .filter(|r| seen.insert((r.kind.clone(), r.plural.clone())))
+        .collect();
+
+    let lists: Vec<_> = stream::iter(types)
+        .map(|r| async move {
+            let ar = build_api_resource(&r.group, &r.version, &r.kind, &r.plural);
+            let list = build_dynamic_api(client, name, &ar, false)
+                .list_metadata(&ListParams::default())
+                .await;
+            (ar, list)
+        })
+        .buffer_unordered(BULK_CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut resource_counts = Vec::new();
+    let mut remaining = Vec::new();
+    for (ar, list) in lists {
+        // Types the user can't list are left out of the counts
+        let Ok(list) = list else { continue };
+        if list.items.is_empty() {
+            continue;
+        }
+        resource_counts.push(NamespaceResourceCount {
+            api_version: ar.api_version.clone(),
+            kind: ar.kind.clone(),
+            count: list.items.len(),
+        });
+        if terminating {
+            for obj in &list.items {
+                let managed = obj.metadata.managed_fields.as_deref().unwrap_or_default();
+                remaining.push(NamespaceRemainingResource {
+                    api_version: ar.api_version.clone(),
+                    kind: ar.kind.clone(),
+                    name: obj.name_any(),
+                    deletion_timestamp: obj
+                        .metadata
+                        .deletion_timestamp
+                        .as_ref()
+                        .map(|t| t.0.to_rfc3339())
+                        .unwrap_or_default(),
+                    finalizers: obj.finalizers().iter().map(|f| describe_finalizer(f, managed)).collect(),
+                });
             }
+        }
+    }
+    resource_counts.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.api_version.cmp(&b.api_version)));
+    remaining.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.name.cmp(&b.name)));
+
+    let status = ns.status.clone().unwrap_or_default();
+    let conditions = status
+        .conditions
+        .unwrap_or_default()
+        .into_iter()
+        .map(|c| ConditionInfo {
+            condition_type: c.type_,
+            status: c.status,
+            reason: c.reason.unwrap_or_default(),
+            message: c.message.unwrap_or_default(),
+            last_transition: format_age(c.last_transition_time.as_ref()),
         })
-        .collect())
+        .collect();
+
+    let mut finalizers: Vec<String> = ns.spec.as_ref().and_then(|s| s.finalizers.clone()).unwrap_or_default();
+    finalizers.extend(ns.finalizers().iter().cloned());
+
+    Ok(NamespaceDetailInfo {
+        name: info.name,
+        status: info.status,
+        age: info.age,
+        deletion_timestamp: ns
+            .metadata
+            .deletion_timestamp
+            .as_ref()
+            .map(|t| t.0.to_rfc3339())
+            .unwrap_or_default(),
+        labels: ns.labels().clone(),
+        annotations: ns.annotations().clone(),
+        finalizers,
+        resource_counts,
+        quotas: policy_repository::list_resource_quotas(client, name).await.unwrap_or_default(),
+        conditions,
+        remaining,
+    })
 }
@@ -0,0 +1,531 @@
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::apps::v1::{Deployment, DeploymentSpec, DeploymentStatus, ReplicaSet};
+use k8s_openapi::api::core::v1::{PodSpec, PodTemplateSpec};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::{
+    api::{ListParams, Patch, PatchParams, PostParams},
+    Api, Client,
+};
+
+use crate::application::services::diff::{diff_lines, has_changes};
+use crate::application::services::formatting::{format_age, format_probe};
+use crate::domain::entities::common::ConditionInfo;
+use crate::domain::entities::workload::*;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::helpers::{fetch_events_for, format_label_selector};
+
+pub const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+
+/// Kubernetes' default when `progressDeadlineSeconds` is unset.
+const DEFAULT_PROGRESS_DEADLINE: i32 = 600;
+
+fn int_or_string(val: &IntOrString) -> String {
+    match val {
+        IntOrString::Int(i) => i.to_string(),
+        IntOrString::String(s) => s.clone(),
+    }
+}
+
+pub fn revision_of(rs: &ReplicaSet) -> String {
+    rs.metadata
+        .annotations
+        .as_ref()
+        .and_then(|a| a.get(REVISION_ANNOTATION))
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Summarize the containers of a pod template (no runtime status).
+pub fn build_template_containers(pod_spec: Option<&PodSpec>) -> Vec<WorkloadContainerInfo> {
+    pod_spec
+        .map(|ps| {
+            ps.containers
+                .iter()
+                .map(|c| {
+                    let resources = c.resources.clone().unwrap_or_default();
+                    let requests = resources.requests.unwrap_or_default();
+                    let limits = resources.limits.unwrap_or_default();
+                    WorkloadContainerInfo {
+                        name: c.name.clone(),
+                        image: c.image.clone().unwrap_or_default(),
+                        ports: c
+                            .ports
+                            .as_deref()
+                            .unwrap_or_default()
+                            .iter()
+                            .map(|p| {
+                                format!("{}/{}", p.container_port, p.protocol.clone().unwrap_or_else(|| "TCP".to_string()))
+                            })
+                            .collect(),
+                        requests_cpu: requests.get("cpu").map(|q| q.0.clone()).unwrap_or_default(),
+                        requests_memory: requests.get("memory").map(|q| q.0.clone()).unwrap_or_default(),
+                        limits_cpu: limits.get("cpu").map(|q| q.0.clone()).unwrap_or_default(),
+                        limits_memory: limits.get("memory").map(|q| q.0.clone()).unwrap_or_default(),
+                        liveness: c.liveness_probe.as_ref().map(format_probe),
+                        readiness: c.readiness_probe.as_ref().map(format_probe),
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// List the ReplicaSets controlled by `deployment`, newest revision first.
+pub async fn list_owned_replicasets(
+    client: &Client,
+    namespace: &str,
+    deployment: &Deployment,
+) -> Result<Vec<ReplicaSet>, DomainError> {
+    let uid = deployment.metadata.uid.clone().unwrap_or_default();
+    let rs_api: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+    let mut owned: Vec<ReplicaSet> = rs_api
+        .list(&ListParams::default())
+        .await?
+        .items
+        .into_iter()
+        .filter(|rs| {
+            rs.metadata
+                .owner_references
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .any(|r| r.kind == "Deployment" && r.uid == uid)
+        })
+        .collect();
+    owned.sort_by_key(|rs| std::cmp::Reverse(revision_of(rs).parse::<i64>().unwrap_or(0)));
+    Ok(owned)
+}
+
+/// Whether the rollout has converged: the controller has seen the latest spec and every
+/// replica is updated and available with no old replicas left.
+pub fn rollout_complete(generation: Option<i64>, spec: &DeploymentSpec, status: &DeploymentStatus) -> bool {
+    let replicas = spec.replicas.unwrap_or(1);
+    status.observed_generation.unwrap_or(0) >= generation.unwrap_or(0)
+        && status.updated_replicas.unwrap_or(0) >= replicas
+        && status.replicas.unwrap_or(0) <= status.updated_replicas.unwrap_or(0)
+        && status.available_replicas.unwrap_or(0) >= status.updated_replicas.unwrap_or(0)
+}
+
+/// Decide whether a rollout is stuck. The controller reports `ProgressDeadlineExceeded` on
+/// the Progressing condition; we also flag rollouts whose last progress is older than the
+/// deadline in case the controller hasn't caught up yet. That fallback only applies while
+/// the controller has seen the current spec and last reported `ReplicaSetUpdated`; any
+/// other state of the condition is trusted as is.
+pub fn rollout_stuck(
+    generation: Option<i64>,
+    spec: &DeploymentSpec,
+    status: &DeploymentStatus,
+    now: DateTime<Utc>,
+) -> (bool, String) {
+    if spec.paused.unwrap_or(false) {
+        return (false, "Rollout is paused".to_string());
+    }
+    let conditions = status.conditions.as_deref().unwrap_or_default();
+    let progressing = conditions.iter().find(|c| c.type_ == "Progressing");
+
+    if let Some(c) = progressing {
+        if c.status == "False" && c.reason.as_deref() == Some("ProgressDeadlineExceeded") {
+            return (true, c.message.clone().unwrap_or_else(|| "Progress deadline exceeded".to_string()));
+        }
+    }
+    if rollout_complete(generation, spec, status) {
+        return (false, "Rollout complete".to_string());
+    }
+
+    let deadline = spec.progress_deadline_seconds.unwrap_or(DEFAULT_PROGRESS_DEADLINE) as i64;
+    let observed_current = status.observed_generation.is_some() && status.observed_generation == generation;
+    let last_progress = progressing
+        .filter(|c| observed_current && c.reason.as_deref() == Some("ReplicaSetUpdated"))
+        .and_then(|c| c.last_update_time.as_ref().or(c.last_transition_time.as_ref()));
+    if let Some(t) = last_progress {
+        let elapsed = now.signed_duration_since(t.0).num_seconds();
+        if elapsed > deadline {
+            return (
+                true,
+                format!("No progress for {}s (progressDeadlineSeconds is {})", elapsed, deadline),
+            );
+        }
+    }
+
+    let failure = conditions
+        .iter()
+        .find(|c| c.type_ == "ReplicaFailure" && c.status == "True")
+        .and_then(|c| c.message.clone());
+    let message = match failure {
+        Some(msg) => format!("Rollout in progress; replica failure: {}", msg),
+        None => format!(
+            "Rollout in progress: {} of {} replicas updated, {} available",
+            status.updated_replicas.unwrap_or(0),
+            spec.replicas.unwrap_or(1),
+            status.available_replicas.unwrap_or(0)
+        ),
+    };
+    (false, message)
+}
+
+pub async fn get_deployment_detail(
+    client: &Client,
+    namespace: &str,
+    deployment_name: &str,
+) -> Result<DeploymentDetailInfo, DomainError> {
+    let api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    let dep = api.get(deployment_name).await?;
+    let owned = list_owned_replicasets(client, namespace, &dep).await?;
+
+    let meta = &dep.metadata;
+    let spec = dep.spec.clone().unwrap_or_default();
+    let status = dep.status.clone().unwrap_or_default();
+
+    let name = meta.name.clone().unwrap_or_default();
+    let created = meta
+        .creation_timestamp
+        .as_ref()
+        .map(|t| t.0.to_rfc3339())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let strategy = spec.strategy.clone().unwrap_or_default();
+    let rolling = strategy.rolling_update.as_ref();
+    let strategy_type = strategy.type_.clone().unwrap_or_else(|| "RollingUpdate".to_string());
+    let (max_surge, max_unavailable) = if strategy_type == "RollingUpdate" {
+        (
+            Some(rolling.and_then(|r| r.max_surge.as_ref()).map(int_or_string).unwrap_or_else(|| "25%".to_string())),
+            Some(
+                rolling
+                    .and_then(|r| r.max_unavailable.as_ref())
+                    .map(int_or_string)
+                    .unwrap_or_else(|| "25%".to_string()),
+            ),
+        )
+    } else {
+        (None, None)
+    };
+
+    let current_revision = meta
+        .annotations
+        .as_ref()
+        .and_then(|a| a.get(REVISION_ANNOTATION))
+        .cloned()
+        .unwrap_or_default();
+
+    let replica_sets = owned
+        .iter()
+        .map(|rs| {
+            let revision = revision_of(rs);
+            let rs_spec = rs.spec.clone().unwrap_or_default();
+            DeploymentReplicaSetInfo {
+                name: rs.metadata.name.clone().unwrap_or_default(),
+                current: !revision.is_empty() && revision == current_revision,
+                revision,
+                replicas: rs_spec.replicas.unwrap_or(0),
+                ready_replicas: rs.status.as_ref().and_then(|s| s.ready_replicas).unwrap_or(0),
+                images: rs_spec
+                    .template
+                    .as_ref()
+                    .and_then(|t| t.spec.as_ref())
+                    .map(|ps| ps.containers.iter().filter_map(|c| c.image.clone()).collect())
+                    .unwrap_or_default(),
+                age: format_age(rs.metadata.creation_timestamp.as_ref()),
+            }
+        })
+        .collect();
+
+    let conditions = status
+        .conditions
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|c| ConditionInfo {
+            condition_type: c.type_,
+            status: c.status,
+            reason: c.reason.unwrap_or_default(),
+            message: c.message.unwrap_or_default(),
+            last_transition: format_age(c.last_transition_time.as_ref()),
+        })
+        .collect();
+
+    let (stuck, rollout_message) = rollout_stuck(meta.generation, &spec, &status, Utc::now());
+    let containers = build_template_containers(spec.template.spec.as_ref());
+    let events = fetch_events_for(client, namespace, &name, "Deployment").await;
+
+    Ok(DeploymentDetailInfo {
+        name,
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        created,
+        labels: meta.labels.clone().unwrap_or_default(),
+        annotations: meta.annotations.clone().unwrap_or_default(),
+        selector: format_label_selector(&spec.selector),
+        replicas: spec.replicas.unwrap_or(1),
+        updated_replicas: status.updated_replicas.unwrap_or(0),
+        ready_replicas: status.ready_replicas.unwrap_or(0),
+        available_replicas: status.available_replicas.unwrap_or(0),
+        unavailable_replicas: status.unavailable_replicas.unwrap_or(0),
+        strategy_type,
+        max_surge,
+        max_unavailable,
+        min_ready_seconds: spec.min_ready_seconds.unwrap_or(0),
+        revision_history_limit: spec.revision_history_limit.unwrap_or(10),
+        progress_deadline_seconds: spec.progress_deadline_seconds.unwrap_or(DEFAULT_PROGRESS_DEADLINE),
+        paused: spec.paused.unwrap_or(false),
+        current_revision,
+        rollout_stuck: stuck,
+        rollout_message,
+        conditions,
+        replica_sets,
+        containers,
+        events,
+    })
+}
+
+/// Resolve the ReplicaSet to roll back to. `None` means the previous revision,
+/// mirroring `kubectl rollout undo` without `--to-revision`.
+fn find_undo_target<'a>(
+    owned: &'a [ReplicaSet],
+    current_revision: &str,
+    revision: Option<i64>,
+) -> Result<&'a ReplicaSet, DomainError> {
+    let current: i64 = current_revision.parse().unwrap_or(0);
+    match revision {
+        Some(r) if r > 0 => owned
+            .iter()
+            .find(|rs| revision_of(rs).parse::<i64>().ok() == Some(r))
+            .ok_or_else(|| DomainError::NotFound(format!("Revision {} not found", r))),
+        _ => owned
+            .iter()
+            .filter(|rs| revision_of(rs).parse::<i64>().map(|r| r < current).unwrap_or(false))
+            .max_by_key(|rs| revision_of(rs).parse::<i64>().unwrap_or(0))
+            .ok_or_else(|| DomainError::NotFound("No previous revision to roll back to".to_string())),
+    }
+}
+
+/// The pod template a ReplicaSet would restore, without the controller-added hash label.
+fn restorable_template(rs: &ReplicaSet) -> PodTemplateSpec {
+    let mut template = rs.spec.as_ref().and_then(|s| s.template.clone()).unwrap_or_default();
+    if let Some(labels) = template.metadata.as_mut().and_then(|m| m.labels.as_mut()) {
+        labels.remove("pod-template-hash");
+    }
+    template
+}
+
+async fn prepare_rollout_undo(
+    client: &Client,
+    namespace: &str,
+    deployment_name: &str,
+    revision: Option<i64>,
+) -> Result<(Deployment, PodTemplateSpec, RolloutUndoPreview), DomainError> {
+    let api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    let dep = api.get(deployment_name).await?;
+    let owned = list_owned_replicasets(client, namespace, &dep).await?;
+
+    let current_revision = dep
+        .metadata
+        .annotations
+        .as_ref()
+        .and_then(|a| a.get(REVISION_ANNOTATION))
+        .cloned()
+        .unwrap_or_default();
+    let target = find_undo_target(&owned, &current_revision, revision)?;
+    let template = restorable_template(target);
+
+    let current_template = dep.spec.as_ref().map(|s| s.template.clone()).unwrap_or_default();
+    let diff = diff_lines(
+        &serde_yaml::to_string(&current_template)?,
+        &serde_yaml::to_string(&template)?,
+    );
+
+    let preview = RolloutUndoPreview {
+        deployment: deployment_name.to_string(),
+        current_revision,
+        target_revision: revision_of(target),
+        target_replica_set: target.metadata.name.clone().unwrap_or_default(),
+        changed: has_changes(&diff),
+        diff,
+    };
+    Ok((dep, template, preview))
+}
+
+pub async fn preview_rollout_undo(
+    client: &Client,
+    namespace: &str,
+    deployment_name: &str,
+    revision: Option<i64>,
+) -> Result<RolloutUndoPreview, DomainError> {
+    let (_, _, preview) = prepare_rollout_undo(client, namespace, deployment_name, revision).await?;
+    Ok(preview)
+}
+
+/// Restore the deployment's pod template from the ReplicaSet of `revision`. The controller
+/// then re-labels that ReplicaSet with a new revision number, as with `kubectl rollout undo`.
+pub async fn rollout_undo(
+    client: &Client,
+    namespace: &str,
+    deployment_name: &str,
+    revision: Option<i64>,
+) -> Result<RolloutUndoPreview, DomainError> {
+    let (mut dep, template, preview) = prepare_rollout_undo(client, namespace, deployment_name, revision).await?;
+
+    if dep.spec.as_ref().and_then(|s| s.paused).unwrap_or(false) {
+        return Err(DomainError::Configuration(
+            "Cannot roll back a paused deployment; resume it first".to_string(),
+        ));
+    }
+    if !preview.changed {
+        return Ok(preview);
+    }
+
+    if let Some(spec) = dep.spec.as_mut() {
+        spec.template = template;
+    }
+    // `replace` carries the resourceVersion we read, so a concurrent edit fails instead of being lost.
+    let api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    api.replace(deployment_name, &PostParams::default(), &dep).await?;
+    Ok(preview)
+}
+
+pub async fn set_deployment_paused(
+    client: &Client,
+    namespace: &str,
+    deployment_name: &str,
+    paused: bool,
+) -> Result<(), DomainError> {
+    let api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    let patch = serde_json::json!({
+        "spec": { "paused": paused }
+    });
+    api.patch(deployment_name, &PatchParams::apply("k0"), &Patch::Merge(&patch))
+        .await?;
+    Ok(())
+}
+
+/// Snapshot of a rollout, in the shape emitted by the rollout-status stream.
+pub fn rollout_progress(dep: &Deployment, new_replica_set: &str) -> RolloutProgressEvent {
+    let spec = dep.spec.clone().unwrap_or_default();
+    let status = dep.status.clone().unwrap_or_default();
+    let generation = dep.metadata.generation;
+
+    let (stuck, message) = rollout_stuck(generation, &spec, &status, Utc::now());
+    let failure_reason = status
+        .conditions
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .find(|c| {
+            (c.type_ == "ReplicaFailure" && c.status == "True")
+                || (c.type_ == "Progressing" && c.status == "False")
+        })
+        .map(|c| match &c.message {
+            Some(m) => format!("{}: {}", c.reason.clone().unwrap_or_default(), m),
+            None => c.reason.clone().unwrap_or_default(),
+        });
+
+    let (phase, done) = if stuck {
+        ("Failed", true)
+    } else if spec.paused.unwrap_or(false) {
+        ("Paused", false)
+    } else if rollout_complete(generation, &spec, &status) {
+        ("Complete", true)
+    } else {
+        ("Progressing", false)
+    };
+
+    RolloutProgressEvent {
+        deployment: dep.metadata.name.clone().unwrap_or_default(),
+        revision: dep
+            .metadata
+            .annotations
+            .as_ref()
+            .and_then(|a| a.get(REVISION_ANNOTATION))
+            .cloned()
+            .unwrap_or_default(),
+        new_replica_set: new_replica_set.to_string(),
+        replicas: spec.replicas.unwrap_or(1),
+        updated_replicas: status.updated_replicas.unwrap_or(0),
+        ready_replicas: status.ready_replicas.unwrap_or(0),
+        available_replicas: status.available_replicas.unwrap_or(0),
+        phase: phase.to_string(),
+        message,
+        failure_reason,
+        done,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::apps::v1::DeploymentCondition;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+    fn spec(replicas: i32) -> DeploymentSpec {
+        DeploymentSpec {
+            replicas: Some(replicas),
+            progress_deadline_seconds: Some(600),
+            ..Default::default()
+        }
+    }
+
+    fn status(observed: i64, replicas: i32, updated: i32, available: i32) -> DeploymentStatus {
+        DeploymentStatus {
+            observed_generation: Some(observed),
+            replicas: Some(replicas),
+            updated_replicas: Some(updated),
+            available_replicas: Some(available),
+            ..Default::default()
+        }
+    }
+
+    fn progressing(status: &str, reason: &str, updated: DateTime<Utc>) -> DeploymentCondition {
+        DeploymentCondition {
+            type_: "Progressing".to_string(),
+            status: status.to_string(),
+            reason: Some(reason.to_string()),
+            message: Some(format!("{} message", reason)),
+            last_update_time: Some(Time(updated)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rollout_complete() {
+        assert!(rollout_complete(Some(2), &spec(3), &status(2, 3, 3, 3)));
+        // Controller hasn't observed the latest spec yet
+        assert!(!rollout_complete(Some(3), &spec(3), &status(2, 3, 3, 3)));
+        // Old replicas still around
+        assert!(!rollout_complete(Some(2), &spec(3), &status(2, 4, 3, 3)));
+        // Updated but not yet available
+        assert!(!rollout_complete(Some(2), &spec(3), &status(2, 3, 3, 2)));
+    }
+
+    #[test]
+    fn test_rollout_stuck() {
+        let now = Utc::now();
+        let long_ago = now - chrono::Duration::seconds(900);
+
+        let mut st = status(2, 3, 1, 1);
+        st.conditions = Some(vec![progressing("False", "ProgressDeadlineExceeded", now)]);
+        let (stuck, message) = rollout_stuck(Some(2), &spec(3), &st, now);
+        assert!(stuck);
+        assert_eq!(message, "ProgressDeadlineExceeded message");
+
+        // No progress past the deadline while the controller reports the rollout as current
+        st.conditions = Some(vec![progressing("True", "ReplicaSetUpdated", long_ago)]);
+        assert!(rollout_stuck(Some(2), &spec(3), &st, now).0);
+
+        // Same age, but the controller hasn't seen the latest spec: trust it for now
+        assert!(!rollout_stuck(Some(3), &spec(3), &st, now).0);
+
+        // Any other reason is the controller's word, not ours to second-guess
+        st.conditions = Some(vec![progressing("True", "NewReplicaSetAvailable", long_ago)]);
+        assert!(!rollout_stuck(Some(2), &spec(3), &st, now).0);
+
+        st.conditions = Some(vec![progressing("True", "ReplicaSetUpdated", now)]);
+        let (stuck, message) = rollout_stuck(Some(2), &spec(3), &st, now);
+        assert!(!stuck);
+        assert_eq!(message, "Rollout in progress: 1 of 3 replicas updated, 1 available");
+
+        let (stuck, message) = rollout_stuck(Some(2), &spec(3), &status(2, 3, 3, 3), now);
+        assert!(!stuck);
+        assert_eq!(message, "Rollout complete");
+
+        let paused = DeploymentSpec { paused: Some(true), ..spec(3) };
+        assert!(!rollout_stuck(Some(2), &paused, &st, now).0);
+    }
+}
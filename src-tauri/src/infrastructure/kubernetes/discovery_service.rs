@@ -0,0 +1,233 @@
+use futures::future::join_all;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::APIResourceList;
+use kube::{discovery::ApiResource, Client};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::domain::entities::common::ApiResourceInfo;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::helpers::build_api_resource;
+
+/// Per-context cache of the resource types served by the API server.
+///
+/// This walks the discovery endpoints itself rather than using `kube::Discovery`, which
+/// drops `shortNames` and `singularName` — both needed to resolve names like "deploy" or "po".
+pub struct DiscoveryService {
+    cache: Arc<Mutex<HashMap<String, Discovered>>>,
+}
+
+/// One discovery run: the resource types (at each group's preferred version) and every
+/// version each group serves, keyed by group name ("" for the core API).
+#[derive(Clone)]
+struct Discovered {
+    resources: Arc<Vec<ApiResourceInfo>>,
+    versions: Arc<HashMap<String, Vec<String>>>,
+}
+
+impl Default for DiscoveryService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DiscoveryService {
+    pub fn new() -> Self {
+        Self {
+            cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// All served resource types for `context`, discovering them on first use.
+    pub async fn resources(&self, client: &Client, context: &str) -> Result<Arc<Vec<ApiResourceInfo>>, DomainError> {
+        Ok(self.discovered(client, context).await?.resources)
+    }
+
+    pub async fn refresh(&self, client: &Client, context: &str) -> Result<Arc<Vec<ApiResourceInfo>>, DomainError> {
+        Ok(self.rediscover(client, context).await?.resources)
+    }
+
+    async fn discovered(&self, client: &Client, context: &str) -> Result<Discovered, DomainError> {
+        if let Some(cached) = self.cache.lock().await.get(context) {
+            return Ok(cached.clone());
+        }
+        self.rediscover(client, context).await
+    }
+
+    async fn rediscover(&self, client: &Client, context: &str) -> Result<Discovered, DomainError> {
+        let discovered = discover(client).await?;
+        self.cache.lock().await.insert(context.to_string(), discovered.clone());
+        Ok(discovered)
+    }
+
+    pub async fn invalidate(&self, context: &str) {
+        self.cache.lock().await.remove(context);
+    }
+
+    /// Resolve a kind, plural, singular or short name (optionally qualified with a group,
+    /// e.g. "deployments.apps"). A miss re-runs discovery once, since CRDs may have been
+    /// installed since the last run.
+    pub async fn resolve(&self, client: &Client, context: &str, query: &str) -> Result<ApiResourceInfo, DomainError> {
+        let cached = self.resources(client, context).await?;
+        let resources = match find_resource(&cached, query) {
+            Some(_) => cached,
+            None => self.refresh(client, context).await?,
+        };
+        find_resource(&resources, query)
+            .cloned()
+            .ok_or_else(|| DomainError::NotFound(format!("Resource type \"{}\" is not served by this cluster", query)))
+    }
+
+    /// Like `resolve`, returning the `ApiResource` and whether the type is cluster-scoped.
+    pub async fn resolve_api_resource(
+        &self,
+        client: &Client,
+        context: &str,
+        query: &str,
+    ) -> Result<(ApiResource, bool), DomainError> {
+        let found = self.resolve(client, context, query).await?;
+        Ok((
+            build_api_resource(&found.group, &found.version, &found.kind, &found.plural),
+            !found.namespaced,
+        ))
+    }
+
+    /// Resolve the exact group and kind a manifest names, returning the `ApiResource` for
+    /// the manifest's own version and whether the type is cluster-scoped. The version must
+    /// be one the group still serves. Like `resolve`, a miss re-runs discovery once.
+    pub async fn resolve_kind(
+        &self,
+        client: &Client,
+        context: &str,
+        group: &str,
+        version: &str,
+        kind: &str,
+    ) -> Result<(ApiResource, bool), DomainError> {
+        let find = |discovered: &Discovered| {
+            let served = discovered.versions.get(group).is_some_and(|vs| vs.iter().any(|v| v == version));
+            let found = discovered.resources.iter().find(|r| r.group == group && r.kind == kind);
+            found.filter(|_| served).cloned()
+        };
+        let found = match find(&self.discovered(client, context).await?) {
+            Some(found) => found,
+            None => find(&self.rediscover(client, context).await?).ok_or_else(|| {
+                let api_version = if group.is_empty() { version.to_string() } else { format!("{}/{}", group, version) };
+                DomainError::NotFound(format!("{} {} is not served by this cluster", api_version, kind))
+            })?,
+        };
+        Ok((build_api_resource(group, version, kind, &found.plural), !found.namespaced))
+    }
+}
+
+fn push_resources(out: &mut Vec<ApiResourceInfo>, group: &str, version: &str, list: APIResourceList) {
+    for r in list.resources {
+        // Skip subresources such as pods/log or deployments/scale
+        if r.name.contains('/') {
+            continue;
+        }
+        out.push(ApiResourceInfo {
+            group: group.to_string(),
+            version: version.to_string(),
+            singular: if r.singular_name.is_empty() { r.kind.to_lowercase() } else { r.singular_name },
+            kind: r.kind,
+            plural: r.name,
+            short_names: r.short_names.unwrap_or_default(),
+            namespaced: r.namespaced,
+            verbs: r.verbs,
+        });
+    }
+}
+
+/// Query the core API and the preferred version of every API group, including aggregated
+/// APIs. Groups whose backing service is unavailable (e.g. metrics-server down) are skipped
+/// so one broken APIService doesn't hide everything else.
+async fn discover(client: &Client) -> Result<Discovered, DomainError> {
+    let mut resources = Vec::new();
+    let mut versions = HashMap::new();
+
+    let core_versions = client.list_core_api_versions().await?.versions;
+    for version in &core_versions {
+        let list = client.list_core_api_resources(version).await?;
+        push_resources(&mut resources, "", version, list);
+    }
+    versions.insert(String::new(), core_versions);
+
+    let groups = client.list_api_groups().await?.groups;
+    for g in &groups {
+        versions.insert(g.name.clone(), g.versions.iter().map(|v| v.version.clone()).collect());
+    }
+    let fetches = groups.iter().filter_map(|g| {
+        let gv = g.preferred_version.as_ref().or_else(|| g.versions.first())?;
+        Some(async move {
+            let list = client.list_api_group_resources(&gv.group_version).await;
+            (g.name.as_str(), gv.version.as_str(), list)
+        })
+    });
+    for (group, version, list) in join_all(fetches).await {
+        if let Ok(list) = list {
+            push_resources(&mut resources, group, version, list);
+        }
+    }
+    Ok(Discovered {
+        resources: Arc::new(resources),
+        versions: Arc::new(versions),
+    })
+}
+
+/// Match in the same order kubectl does: kind, then plural, singular and short names.
+/// Resources are kept in discovery order, so the core group wins ties (e.g. "Event").
+pub fn find_resource<'a>(resources: &'a [ApiResourceInfo], query: &str) -> Option<&'a ApiResourceInfo> {
+    let query = query.trim().to_lowercase();
+    let (name, group) = match query.split_once('.') {
+        Some((name, group)) => (name, Some(group)),
+        None => (query.as_str(), None),
+    };
+    let candidates: Vec<&ApiResourceInfo> = resources
+        .iter()
+        .filter(|r| group.is_none_or(|g| r.group == g))
+        .collect();
+
+    candidates
+        .iter()
+        .find(|r| r.kind.to_lowercase() == name)
+        .or_else(|| candidates.iter().find(|r| r.plural == name))
+        .or_else(|| candidates.iter().find(|r| r.singular == name))
+        .or_else(|| candidates.iter().find(|r| r.short_names.iter().any(|s| s == name)))
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn res(group: &str, kind: &str, plural: &str, short: &[&str]) -> ApiResourceInfo {
+        ApiResourceInfo {
+            group: group.to_string(),
+            version: "v1".to_string(),
+            kind: kind.to_string(),
+            plural: plural.to_string(),
+            singular: kind.to_lowercase(),
+            short_names: short.iter().map(|s| s.to_string()).collect(),
+            namespaced: true,
+            verbs: vec![],
+        }
+    }
+
+    #[test]
+    fn test_find_resource() {
+        let resources = vec![
+            res("", "Event", "events", &["ev"]),
+            res("apps", "Deployment", "deployments", &["deploy"]),
+            res("events.k8s.io", "Event", "events", &["ev"]),
+            res("networking.k8s.io", "Ingress", "ingresses", &["ing"]),
+        ];
+        let find = |q: &str| find_resource(&resources, q).map(|r| (r.group.as_str(), r.kind.as_str()));
+
+        assert_eq!(find("deploy"), Some(("apps", "Deployment")));
+        assert_eq!(find("Deployment"), Some(("apps", "Deployment")));
+        assert_eq!(find("ingress"), Some(("networking.k8s.io", "Ingress")));
+        assert_eq!(find("events"), Some(("", "Event")));
+        assert_eq!(find("events.events.k8s.io"), Some(("events.k8s.io", "Event")));
+        assert_eq!(find("widgets"), None);
+    }
+}
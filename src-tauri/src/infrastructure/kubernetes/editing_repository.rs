@@ -1,71 +1,110 @@
+use std::time::Duration;
+
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
 use kube::{
     api::{Api, DynamicObject, Patch, PatchParams, PostParams},
     discovery::ApiResource,
+    runtime::wait::{await_condition, conditions},
     Client,
 };
+use serde::Deserialize;
 use serde_json::Value;
 
+use crate::application::services::diff::{diff_lines, has_changes};
+use crate::application::services::formatting::format_age;
+use crate::application::services::managed_fields;
+use crate::application::services::yaml_positions::YamlPositions;
+use crate::domain::entities::common::{
+    ApplyResult, DryRunResult, FieldManagerInfo, FieldOwnerInfo, FieldOwnershipInfo, ManifestApplyResult,
+};
 use crate::domain::errors::DomainError;
-
-fn build_api_resource(group: &str, version: &str, kind: &str, plural: &str) -> ApiResource {
-    ApiResource {
-        group: group.to_string(),
-        version: version.to_string(),
-        kind: kind.to_string(),
-        api_version: if group.is_empty() {
-            version.to_string()
-        } else {
-            format!("{}/{}", group, version)
-        },
-        plural: plural.to_string(),
-    }
-}
-
-fn build_api(client: &Client, ns: &str, ar: &ApiResource, cluster_scoped: bool) -> Api<DynamicObject> {
-    if cluster_scoped {
-        Api::all_with(client.clone(), ar)
-    } else {
-        Api::namespaced_with(client.clone(), ns, ar)
-    }
-}
+use crate::infrastructure::kubernetes::discovery_service::DiscoveryService;
+use crate::infrastructure::kubernetes::helpers::{build_dynamic_api, parse_apply_conflicts};
 
 pub async fn get_resource_yaml(
     client: &Client,
     ns: &str,
     name: &str,
-    group: &str,
-    version: &str,
-    kind: &str,
-    plural: &str,
+    ar: &ApiResource,
     cluster_scoped: bool,
 ) -> Result<String, DomainError> {
-    let ar = build_api_resource(group, version, kind, plural);
-    let api = build_api(client, ns, &ar, cluster_scoped);
+    let api = build_dynamic_api(client, ns, ar, cluster_scoped);
     let obj = api.get(name).await?;
+    Ok(editor_yaml(&obj)?.1)
+}
 
-    let mut val = serde_json::to_value(&obj)?;
+/// The object as shown in the YAML editor, without managedFields, plus its YAML.
+fn editor_yaml(obj: &DynamicObject) -> Result<(Value, String), DomainError> {
+    let mut val = serde_json::to_value(obj)?;
     if let Some(metadata) = val.get_mut("metadata").and_then(|m| m.as_object_mut()) {
         metadata.remove("managedFields");
     }
-
     let yaml = serde_yaml::to_string(&val)?;
-    Ok(yaml)
+    Ok((val, yaml))
 }
 
-pub async fn update_resource_yaml(
+/// Which field manager owns each field, from the object's managedFields, with positions
+/// in the same YAML `get_resource_yaml` returns so the editor can annotate those lines.
+pub async fn get_field_ownership(
     client: &Client,
     ns: &str,
     name: &str,
-    group: &str,
-    version: &str,
-    kind: &str,
-    plural: &str,
-    yaml_content: &str,
+    ar: &ApiResource,
     cluster_scoped: bool,
-) -> Result<(), DomainError> {
-    let ar = build_api_resource(group, version, kind, plural);
-    let api = build_api(client, ns, &ar, cluster_scoped);
+) -> Result<FieldOwnershipInfo, DomainError> {
+    let api = build_dynamic_api(client, ns, ar, cluster_scoped);
+    let obj = api.get(name).await?;
+    let (val, yaml) = editor_yaml(&obj)?;
+    let positions = YamlPositions::index(&yaml, 0);
+
+    let mut managers = Vec::new();
+    let mut fields = Vec::new();
+    for entry in obj.metadata.managed_fields.as_deref().unwrap_or_default() {
+        let manager = entry.manager.clone().unwrap_or_default();
+        let operation = entry.operation.clone().unwrap_or_default();
+        let subresource = entry.subresource.clone().unwrap_or_default();
+        let time = entry.time.as_ref().map(|t| t.0.to_rfc3339()).unwrap_or_default();
+        let paths = entry
+            .fields_v1
+            .as_ref()
+            .map(|f| managed_fields::owned_paths(&f.0, &val))
+            .unwrap_or_default();
 
+        managers.push(FieldManagerInfo {
+            manager: manager.clone(),
+            operation: operation.clone(),
+            api_version: entry.api_version.clone().unwrap_or_default(),
+            subresource: subresource.clone(),
+            time: time.clone(),
+            age: format_age(entry.time.as_ref()),
+            field_count: paths.len(),
+        });
+        for path in paths {
+            let (line, column) = positions.locate(&path, 0);
+            fields.push(FieldOwnerInfo {
+                path,
+                manager: manager.clone(),
+                operation: operation.clone(),
+                subresource: subresource.clone(),
+                time: time.clone(),
+                line,
+                column,
+            });
+        }
+    }
+    fields.sort_by(|a, b| (a.line, &a.path, &a.manager).cmp(&(b.line, &b.path, &b.manager)));
+
+    Ok(FieldOwnershipInfo { yaml, managers, fields })
+}
+
+async fn replace_from_yaml(
+    api: &Api<DynamicObject>,
+    ns: &str,
+    name: &str,
+    yaml_content: &str,
+    cluster_scoped: bool,
+    pp: &PostParams,
+) -> Result<(DynamicObject, DynamicObject), DomainError> {
     let mut obj: DynamicObject = serde_yaml::from_str(yaml_content)?;
     obj.metadata.name = Some(name.to_string());
     if !cluster_scoped {
@@ -73,26 +112,311 @@ pub async fn update_resource_yaml(
     }
 
     let current = api.get(name).await?;
-    obj.metadata.resource_version = current.metadata.resource_version;
+    obj.metadata.resource_version = current.metadata.resource_version.clone();
+
+    let updated = api.replace(name, pp, &obj).await?;
+    Ok((current, updated))
+}
+
+/// YAML used for dry-run diffs: status, managedFields and the bookkeeping the server bumps
+/// on every write (resourceVersion, generation) only add noise to a review.
+fn diffable_yaml(obj: &DynamicObject) -> Result<String, DomainError> {
+    let mut val = serde_json::to_value(obj)?;
+    if let Some(root) = val.as_object_mut() {
+        root.remove("status");
+        if let Some(metadata) = root.get_mut("metadata").and_then(|m| m.as_object_mut()) {
+            for field in ["managedFields", "resourceVersion", "generation"] {
+                metadata.remove(field);
+            }
+        }
+    }
+    Ok(serde_yaml::to_string(&val)?)
+}
+
+fn dry_run_result(current: &DynamicObject, updated: &DynamicObject) -> Result<DryRunResult, DomainError> {
+    let yaml = diffable_yaml(updated)?;
+    let diff = diff_lines(&diffable_yaml(current)?, &yaml);
+    Ok(DryRunResult { yaml, changed: has_changes(&diff), diff })
+}
 
-    let pp = PostParams::default();
-    api.replace(name, &pp, &obj).await?;
+pub async fn update_resource_yaml(
+    client: &Client,
+    ns: &str,
+    name: &str,
+    ar: &ApiResource,
+    yaml_content: &str,
+    cluster_scoped: bool,
+) -> Result<(), DomainError> {
+    let api = build_dynamic_api(client, ns, ar, cluster_scoped);
+    replace_from_yaml(&api, ns, name, yaml_content, cluster_scoped, &PostParams::default()).await?;
     Ok(())
 }
 
+/// Run `update_resource_yaml` with `dryRun=All`, returning the object as the server would
+/// persist it (defaulting and admission webhook mutations included) and its diff from live.
+pub async fn dry_run_resource_yaml(
+    client: &Client,
+    ns: &str,
+    name: &str,
+    ar: &ApiResource,
+    yaml_content: &str,
+    cluster_scoped: bool,
+) -> Result<DryRunResult, DomainError> {
+    let api = build_dynamic_api(client, ns, ar, cluster_scoped);
+    let pp = PostParams { dry_run: true, ..Default::default() };
+    let (current, updated) = replace_from_yaml(&api, ns, name, yaml_content, cluster_scoped, &pp).await?;
+    dry_run_result(&current, &updated)
+}
+
+/// Server-side apply the edited YAML as the `k0` field manager. Only the fields present in
+/// the document are claimed, so fields owned by controllers are left alone unless they were
+/// changed, in which case the conflicting fields and their managers are returned.
+pub async fn apply_resource_yaml(
+    client: &Client,
+    ns: &str,
+    name: &str,
+    ar: &ApiResource,
+    yaml_content: &str,
+    force: bool,
+    cluster_scoped: bool,
+) -> Result<ApplyResult, DomainError> {
+    let api = build_dynamic_api(client, ns, ar, cluster_scoped);
+
+    let mut obj: Value = serde_yaml::from_str(yaml_content)?;
+    let root = obj
+        .as_object_mut()
+        .ok_or_else(|| DomainError::Serialization("YAML document must be a mapping".to_string()))?;
+    root.insert("apiVersion".into(), Value::String(ar.api_version.clone()));
+    root.insert("kind".into(), Value::String(ar.kind.clone()));
+    // Status is written by controllers; applying it would only produce spurious conflicts
+    root.remove("status");
+
+    let metadata = root
+        .entry("metadata")
+        .or_insert_with(|| Value::Object(Default::default()));
+    if let Some(meta) = metadata.as_object_mut() {
+        for field in ["resourceVersion", "uid", "creationTimestamp", "generation", "managedFields", "selfLink"] {
+            meta.remove(field);
+        }
+        meta.insert("name".into(), Value::String(name.to_string()));
+        if !cluster_scoped {
+            meta.insert("namespace".into(), Value::String(ns.to_string()));
+        }
+    }
+
+    let mut pp = PatchParams::apply("k0");
+    if force {
+        pp = pp.force();
+    }
+
+    match api.patch(name, &pp, &Patch::Apply(&obj)).await {
+        Ok(_) => Ok(ApplyResult { applied: true, forced: force, conflicts: Vec::new() }),
+        Err(kube::Error::Api(resp)) if resp.code == 409 => {
+            let conflicts = parse_apply_conflicts(&resp.message);
+            if conflicts.is_empty() {
+                return Err(DomainError::KubernetesApi(resp.message));
+            }
+            Ok(ApplyResult { applied: false, forced: force, conflicts })
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Split a multi-document YAML stream into objects, expanding `kind: List` wrappers.
+fn split_manifests(yaml_content: &str) -> Result<Vec<Value>, DomainError> {
+    let mut docs = Vec::new();
+    for de in serde_yaml::Deserializer::from_str(yaml_content) {
+        let doc = Value::deserialize(de)?;
+        if doc.is_null() {
+            continue;
+        }
+        if doc["kind"] == "List" {
+            docs.extend(doc["items"].as_array().cloned().unwrap_or_default());
+        } else {
+            docs.push(doc);
+        }
+    }
+    Ok(docs)
+}
+
+/// Namespaces and CRDs have to exist before the objects that live in or use them.
+fn apply_order(kind: &str) -> u8 {
+    match kind {
+        "Namespace" => 0,
+        "CustomResourceDefinition" => 1,
+        _ => 2,
+    }
+}
+
+/// How long a newly written CRD gets to become Established before its instances are sent.
+const CRD_ESTABLISH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Wait for every CRD written so far to be Established, so objects of the new types can be
+/// resolved and accepted. A CRD that doesn't get there in time has the reason recorded on
+/// its result; its instances then fail on their own.
+async fn await_crds_established(client: &Client, results: &mut [ManifestApplyResult]) {
+    let api: Api<CustomResourceDefinition> = Api::all(client.clone());
+    for result in results.iter_mut().filter(|r| r.success && r.kind == "CustomResourceDefinition") {
+        let established = await_condition(api.clone(), &result.name, conditions::is_crd_established());
+        match tokio::time::timeout(CRD_ESTABLISH_TIMEOUT, established).await {
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => result.error = format!("Applied, but waiting for it to be Established failed: {}", e),
+            Err(_) => {
+                result.error = format!(
+                    "Applied, but not Established after {}s",
+                    CRD_ESTABLISH_TIMEOUT.as_secs()
+                )
+            }
+        }
+    }
+}
+
+/// Resolve a manifest's kind through the cached discovery for `context` and default its
+/// namespace. On success returns the API to write it through; on failure `result.error`
+/// says why.
+async fn prepare_manifest(
+    client: &Client,
+    discovery: &DiscoveryService,
+    context: &str,
+    default_ns: &str,
+    doc: &mut Value,
+    result: &mut ManifestApplyResult,
+) -> Option<Api<DynamicObject>> {
+    let api_version = doc["apiVersion"].as_str().unwrap_or_default().to_string();
+    let kind = doc["kind"].as_str().unwrap_or_default().to_string();
+    result.api_version = api_version.clone();
+    result.kind = kind.clone();
+    result.name = doc["metadata"]["name"].as_str().unwrap_or_default().to_string();
+    if api_version.is_empty() || kind.is_empty() || result.name.is_empty() {
+        result.error = "Document is missing apiVersion, kind or metadata.name".to_string();
+        return None;
+    }
+
+    let (group, version) = api_version.split_once('/').unwrap_or(("", &api_version));
+    let (ar, cluster_scoped) = match discovery.resolve_kind(client, context, group, version, &kind).await {
+        Ok(found) => found,
+        Err(e) => {
+            result.error = format!("Unable to resolve {} {}: {}", api_version, kind, e);
+            return None;
+        }
+    };
+
+    if let Some(meta) = doc.get_mut("metadata").and_then(|m| m.as_object_mut()) {
+        if cluster_scoped {
+            meta.remove("namespace");
+        } else {
+            let ns = meta
+                .get("namespace")
+                .and_then(|n| n.as_str())
+                .filter(|n| !n.is_empty())
+                .unwrap_or(default_ns)
+                .to_string();
+            meta.insert("namespace".into(), Value::String(ns.clone()));
+            result.namespace = ns;
+        }
+    }
+    Some(build_dynamic_api(client, &result.namespace, &ar, cluster_scoped))
+}
+
+fn empty_result() -> ManifestApplyResult {
+    ManifestApplyResult {
+        api_version: String::new(),
+        kind: String::new(),
+        name: String::new(),
+        namespace: String::new(),
+        success: false,
+        error: String::new(),
+        conflicts: Vec::new(),
+    }
+}
+
+async fn apply_manifest(
+    client: &Client,
+    discovery: &DiscoveryService,
+    context: &str,
+    default_ns: &str,
+    mut doc: Value,
+    force: bool,
+) -> ManifestApplyResult {
+    let mut result = empty_result();
+    let Some(api) = prepare_manifest(client, discovery, context, default_ns, &mut doc, &mut result).await else {
+        return result;
+    };
+
+    let mut pp = PatchParams::apply("k0");
+    if force {
+        pp = pp.force();
+    }
+    match api.patch(&result.name, &pp, &Patch::Apply(&doc)).await {
+        Ok(_) => result.success = true,
+        Err(kube::Error::Api(resp)) if resp.code == 409 => {
+            result.conflicts = parse_apply_conflicts(&resp.message);
+            result.error = resp.message;
+        }
+        Err(e) => result.error = e.to_string(),
+    }
+    result
+}
+
+async fn create_manifest(
+    client: &Client,
+    discovery: &DiscoveryService,
+    context: &str,
+    default_ns: &str,
+    mut doc: Value,
+    dry_run: bool,
+) -> ManifestApplyResult {
+    let mut result = empty_result();
+    let Some(api) = prepare_manifest(client, discovery, context, default_ns, &mut doc, &mut result).await else {
+        return result;
+    };
+
+    let obj: DynamicObject = match serde_json::from_value(doc) {
+        Ok(obj) => obj,
+        Err(e) => {
+            result.error = e.to_string();
+            return result;
+        }
+    };
+    let pp = PostParams { dry_run, field_manager: Some("k0".to_string()) };
+    match api.create(&pp, &obj).await {
+        Ok(_) => result.success = true,
+        Err(e) => result.error = e.to_string(),
+    }
+    result
+}
+
+/// How `write_manifests` sends each object to the server.
+#[derive(Clone, Copy)]
+enum WriteMode {
+    Apply { force: bool },
+    Create { dry_run: bool },
+}
+
+/// Server-side apply every object in a multi-document YAML stream. Kinds are resolved
+/// through discovery, so plurals and scope are always correct, and objects are applied
+/// Namespaces first, then CRDs, then everything else; new CRDs are given time to become
+/// Established before their instances are applied. A failure doesn't stop the rest.
+pub async fn apply_manifests(
+    client: &Client,
+    discovery: &DiscoveryService,
+    context: &str,
+    ns: &str,
+    yaml_content: &str,
+    force: bool,
+) -> Result<Vec<ManifestApplyResult>, DomainError> {
+    write_manifests(client, discovery, context, ns, yaml_content, WriteMode::Apply { force }).await
+}
+
 pub async fn patch_resource(
     client: &Client,
     ns: &str,
     name: &str,
-    group: &str,
-    version: &str,
-    kind: &str,
-    plural: &str,
+    ar: &ApiResource,
     patch_json: &Value,
     cluster_scoped: bool,
 ) -> Result<(), DomainError> {
-    let ar = build_api_resource(group, version, kind, plural);
-    let api = build_api(client, ns, &ar, cluster_scoped);
+    let api = build_dynamic_api(client, ns, ar, cluster_scoped);
 
     let patch = Patch::Strategic(patch_json);
     let pp = PatchParams::default();
@@ -100,18 +424,80 @@ pub async fn patch_resource(
     Ok(())
 }
 
+pub async fn dry_run_patch_resource(
+    client: &Client,
+    ns: &str,
+    name: &str,
+    ar: &ApiResource,
+    patch_json: &Value,
+    cluster_scoped: bool,
+) -> Result<DryRunResult, DomainError> {
+    let api = build_dynamic_api(client, ns, ar, cluster_scoped);
+
+    let current = api.get(name).await?;
+    let patch = Patch::Strategic(patch_json);
+    let pp = PatchParams::default().dry_run();
+    let updated = api.patch(name, &pp, &patch).await?;
+    dry_run_result(&current, &updated)
+}
+
 pub async fn delete_resource(
     client: &Client,
     ns: &str,
     name: &str,
-    group: &str,
-    version: &str,
-    kind: &str,
-    plural: &str,
+    ar: &ApiResource,
     cluster_scoped: bool,
 ) -> Result<(), DomainError> {
-    let ar = build_api_resource(group, version, kind, plural);
-    let api = build_api(client, ns, &ar, cluster_scoped);
+    let api = build_dynamic_api(client, ns, ar, cluster_scoped);
     api.delete(name, &Default::default()).await?;
     Ok(())
 }
+
+/// Create every object in a multi-document YAML stream, failing per object if it already
+/// exists. With `dry_run` the server runs validation and admission without persisting.
+/// Ordering and CRD handling match `apply_manifests`.
+pub async fn create_manifests(
+    client: &Client,
+    discovery: &DiscoveryService,
+    context: &str,
+    ns: &str,
+    yaml_content: &str,
+    dry_run: bool,
+) -> Result<Vec<ManifestApplyResult>, DomainError> {
+    write_manifests(client, discovery, context, ns, yaml_content, WriteMode::Create { dry_run }).await
+}
+
+async fn write_manifests(
+    client: &Client,
+    discovery: &DiscoveryService,
+    context: &str,
+    ns: &str,
+    yaml_content: &str,
+    mode: WriteMode,
+) -> Result<Vec<ManifestApplyResult>, DomainError> {
+    let mut docs = split_manifests(yaml_content)?;
+    if docs.is_empty() {
+        return Err(DomainError::Configuration("No objects found in YAML".to_string()));
+    }
+    docs.sort_by_key(|d| apply_order(d["kind"].as_str().unwrap_or_default()));
+
+    // A dry-run CRD is never persisted, so there is nothing to wait for
+    let persists = !matches!(mode, WriteMode::Create { dry_run: true });
+    let mut results = Vec::with_capacity(docs.len());
+    let mut crds_written = false;
+    for doc in docs {
+        if crds_written && apply_order(doc["kind"].as_str().unwrap_or_default()) > 1 {
+            await_crds_established(client, &mut results).await;
+            // Pick up the new types; a failed refresh leaves them to the miss-triggered one
+            let _ = discovery.refresh(client, context).await;
+            crds_written = false;
+        }
+        let result = match mode {
+            WriteMode::Apply { force } => apply_manifest(client, discovery, context, ns, doc, force).await,
+            WriteMode::Create { dry_run } => create_manifest(client, discovery, context, ns, doc, dry_run).await,
+        };
+        crds_written |= persists && result.success && result.kind == "CustomResourceDefinition";
+        results.push(result);
+    }
+    Ok(results)
+}
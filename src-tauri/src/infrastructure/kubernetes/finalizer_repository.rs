@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use futures::{stream, StreamExt};
+use k8s_openapi::api::core::v1::Namespace;
+use kube::{
+    api::{ListParams, Patch, PatchParams},
+    discovery::ApiResource,
+    Api, Client, ResourceExt,
+};
+
+use crate::application::services::finalizers::describe_finalizer;
+use crate::application::services::formatting::format_age;
+use crate::domain::entities::common::{ApiResourceInfo, StuckResourceInfo};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::bulk_repository::BULK_CONCURRENCY;
+use crate::infrastructure::kubernetes::helpers::{build_api_resource, build_dynamic_api};
+
+async fn stuck_of_type(
+    client: &Client,
+    namespace: Option<&str>,
+    resource: &ApiResourceInfo,
+    min_seconds: i64,
+) -> Vec<StuckResourceInfo> {
+    let ar = build_api_resource(&resource.group, &resource.version, &resource.kind, &resource.plural);
+    let api = match namespace {
+        Some(ns) if resource.namespaced => build_dynamic_api(client, ns, &ar, false),
+        _ => build_dynamic_api(client, "", &ar, true),
+    };
+    // Types the user can't list are skipped rather than failing the whole scan
+    let Ok(list) = api.list_metadata(&ListParams::default()).await else {
+        return Vec::new();
+    };
+    // A Namespace's own finalizer ("kubernetes") lives in spec.finalizers, not metadata
+    let spec_finalizers = if resource.group.is_empty() && resource.kind == "Namespace" {
+        namespace_spec_finalizers(client).await
+    } else {
+        HashMap::new()
+    };
+
+    let now = Utc::now();
+    list.items
+        .iter()
+        .filter_map(|obj| {
+            let deleted_at = obj.metadata.deletion_timestamp.as_ref()?;
+            let seconds = now.signed_duration_since(deleted_at.0).num_seconds();
+            if seconds < min_seconds {
+                return None;
+            }
+            let managed = obj.metadata.managed_fields.as_deref().unwrap_or_default();
+            let spec = spec_finalizers.get(&obj.name_any()).map(Vec::as_slice).unwrap_or_default();
+            Some(StuckResourceInfo {
+                api_version: ar.api_version.clone(),
+                kind: resource.kind.clone(),
+                name: obj.name_any(),
+                namespace: obj.namespace().unwrap_or_default(),
+                deletion_timestamp: deleted_at.0.to_rfc3339(),
+                terminating_for: format_age(Some(deleted_at)),
+                terminating_seconds: seconds,
+                finalizers: obj
+                    .finalizers()
+                    .iter()
+                    .chain(spec)
+                    .map(|f| describe_finalizer(f, managed))
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+async fn namespace_spec_finalizers(client: &Client) -> HashMap<String, Vec<String>> {
+    let api: Api<Namespace> = Api::all(client.clone());
+    let Ok(list) = api.list(&ListParams::default()).await else {
+        return HashMap::new();
+    };
+    list.items
+        .into_iter()
+        .filter(|ns| ns.metadata.deletion_timestamp.is_some())
+        .map(|ns| {
+            let finalizers = ns.spec.and_then(|s| s.finalizers).unwrap_or_default();
+            (ns.metadata.name.unwrap_or_default(), finalizers)
+        })
+        .collect()
+}
+
+/// Objects whose deletion has been pending for at least `min_seconds`, across the given
+/// resource types, longest-stuck first. With a namespace, namespaced types are limited to
+/// it while cluster-scoped ones (Namespaces, PersistentVolumes, ...) are always included.
+/// Only object metadata is fetched, except for Namespaces' spec.finalizers.
+pub async fn find_stuck_resources(
+    client: &Client,
+    namespace: Option<&str>,
+    resources: &[ApiResourceInfo],
+    min_seconds: i64,
+) -> Vec<StuckResourceInfo> {
+    let mut stuck: Vec<StuckResourceInfo> = stream::iter(resources)
+        .map(|r| stuck_of_type(client, namespace, r, min_seconds))
+        .buffer_unordered(BULK_CONCURRENCY)
+        .flat_map(stream::iter)
+        .collect()
+        .await;
+    stuck.sort_by_key(|s| std::cmp::Reverse(s.terminating_seconds));
+    stuck
+}
+
+/// Remove one finalizer from an object that is already being deleted. The patch carries
+/// the resourceVersion it was computed from, so it fails instead of clobbering a
+/// concurrent change to the finalizer list. Returns the finalizers left on the object.
+pub async fn remove_finalizer(
+    client: &Client,
+    ns: &str,
+    name: &str,
+    ar: &ApiResource,
+    cluster_scoped: bool,
+    finalizer: &str,
+) -> Result<Vec<String>, DomainError> {
+    let api = build_dynamic_api(client, ns, ar, cluster_scoped);
+    let obj = api.get(name).await?;
+
+    if obj.metadata.deletion_timestamp.is_none() {
+        return Err(DomainError::Configuration(format!(
+            "{} {} is not being deleted; delete it first and only remove finalizers if it gets stuck",
+            ar.kind, name
+        )));
+    }
+    let finalizers = obj.finalizers();
+    if !finalizers.iter().any(|f| f == finalizer) {
+        let hint = if ar.kind == "Namespace" {
+            " (the namespace controller clears spec.finalizers once the namespace is empty)"
+        } else {
+            ""
+        };
+        return Err(DomainError::NotFound(format!(
+            "Finalizer {} is not set on {} {}{}",
+            finalizer, ar.kind, name, hint
+        )));
+    }
+
+    let remaining: Vec<String> = finalizers.iter().filter(|f| *f != finalizer).cloned().collect();
+    let patch = serde_json::json!({
+        "metadata": {
+            "resourceVersion": obj.resource_version(),
+            "finalizers": remaining,
+        }
+    });
+    api.patch(name, &PatchParams::default(), &Patch::Merge(&patch)).await?;
+    Ok(remaining)
+}
@@ -1,10 +1,11 @@
 use k8s_openapi::api::events::v1::Event;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
 use kube::api::{ApiResource, DynamicObject, ListParams};
 use kube::{Api, Client};
 use std::collections::BTreeMap;
 
 use crate::application::services::formatting::format_age;
-use crate::domain::entities::common::{EventInfo, GenericResourceDetailInfo, OwnerRefInfo};
+use crate::domain::entities::common::{EventInfo, FieldConflict, GenericResourceDetailInfo, OwnerRefInfo};
 use crate::domain::errors::DomainError;
 
 // ---------------------------------------------------------------------------
@@ -41,6 +42,30 @@ pub fn build_dynamic_api(
     }
 }
 
+/// Render a `LabelSelector` the way kubectl prints it, e.g. `app=web,tier in (fe,be)`.
+pub fn format_label_selector(selector: &LabelSelector) -> String {
+    let mut parts: Vec<String> = selector
+        .match_labels
+        .as_ref()
+        .map(|ml| ml.iter().map(|(k, v)| format!("{}={}", k, v)).collect())
+        .unwrap_or_default();
+    for req in selector.match_expressions.as_deref().unwrap_or_default() {
+        let values = req.values.as_deref().unwrap_or_default().join(",");
+        parts.push(match req.operator.as_str() {
+            "In" => format!("{} in ({})", req.key, values),
+            "NotIn" => format!("{} notin ({})", req.key, values),
+            "Exists" => req.key.clone(),
+            "DoesNotExist" => format!("!{}", req.key),
+            op => format!("{} {} ({})", req.key, op, values),
+        });
+    }
+    if parts.is_empty() {
+        "<all>".to_string()
+    } else {
+        parts.join(",")
+    }
+}
+
 pub async fn fetch_events_for(client: &Client, namespace: &str, name: &str, kind: &str) -> Vec<EventInfo> {
     let events_api: Api<Event> = Api::namespaced(client.clone(), namespace);
     let events_lp = ListParams::default()
@@ -70,14 +95,10 @@ pub async fn get_generic_resource_detail(
     client: &Client,
     namespace: &str,
     name: &str,
-    group: &str,
-    version: &str,
-    kind: &str,
-    plural: &str,
+    ar: &ApiResource,
     cluster_scoped: bool,
 ) -> Result<GenericResourceDetailInfo, DomainError> {
-    let ar = build_api_resource(group, version, kind, plural);
-    let api = build_dynamic_api(client, namespace, &ar, cluster_scoped);
+    let api = build_dynamic_api(client, namespace, ar, cluster_scoped);
     let obj = api.get(name).await?;
 
     let meta = obj.metadata;
@@ -116,7 +137,7 @@ pub async fn get_generic_resource_detail(
         }
     }
 
-    let events = fetch_events_for(client, namespace, &res_name, kind).await;
+    let events = fetch_events_for(client, namespace, &res_name, &ar.kind).await;
 
     Ok(GenericResourceDetailInfo {
         name: res_name,
@@ -132,3 +153,84 @@ pub async fn get_generic_resource_detail(
         events,
     })
 }
+
+/// Parse the field conflicts out of a server-side apply 409 message. The API server only
+/// reports them as text, e.g.
+/// `Apply failed with 2 conflicts: conflicts with "kubectl" using apps/v1:\n- .spec.replicas\n- .spec.paused`
+/// or, for a single conflict, `conflict with "kubectl" using apps/v1: .spec.replicas`.
+pub fn parse_apply_conflicts(message: &str) -> Vec<FieldConflict> {
+    let mut conflicts = Vec::new();
+    let mut current: Option<(String, String)> = None;
+
+    for line in message.lines() {
+        let line = line.trim();
+        if let Some(field) = line.strip_prefix("- ") {
+            if let Some((manager, api_version)) = &current {
+                conflicts.push(FieldConflict {
+                    manager: manager.clone(),
+                    api_version: api_version.clone(),
+                    field: field.trim().to_string(),
+                });
+            }
+            continue;
+        }
+
+        let Some(start) = line.find("with \"") else { continue };
+        let rest = &line[start + 6..];
+        let Some(end) = rest.find('"') else { continue };
+        let manager = rest[..end].to_string();
+        let after = &rest[end + 1..];
+        let (api_version, tail) = match after.strip_prefix(" using ") {
+            Some(using) => using.split_once(':').unwrap_or((using, "")),
+            None => ("", after.strip_prefix(':').unwrap_or(after)),
+        };
+
+        let tail = tail.trim();
+        if !tail.is_empty() {
+            conflicts.push(FieldConflict {
+                manager: manager.clone(),
+                api_version: api_version.to_string(),
+                field: tail.to_string(),
+            });
+        }
+        current = Some((manager, api_version.to_string()));
+    }
+    conflicts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_apply_conflicts() {
+        let single = "Apply failed with 1 conflict: conflict with \"kube-controller-manager\" using apps/v1: .spec.replicas";
+        assert_eq!(
+            parse_apply_conflicts(single),
+            vec![FieldConflict {
+                manager: "kube-controller-manager".to_string(),
+                api_version: "apps/v1".to_string(),
+                field: ".spec.replicas".to_string(),
+            }]
+        );
+
+        let multi = "Apply failed with 3 conflicts: conflicts with \"kubectl-edit\" using apps/v1:\n\
+                     - .spec.replicas\n\
+                     - .spec.template.spec.containers[name=\"web\"].image\n\
+                     conflicts with \"helm\":\n\
+                     - .metadata.labels.app\n";
+        let parsed = parse_apply_conflicts(multi);
+        let fields: Vec<(&str, &str, &str)> = parsed
+            .iter()
+            .map(|c| (c.manager.as_str(), c.api_version.as_str(), c.field.as_str()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("kubectl-edit", "apps/v1", ".spec.replicas"),
+                ("kubectl-edit", "apps/v1", ".spec.template.spec.containers[name=\"web\"].image"),
+                ("helm", "", ".metadata.labels.app"),
+            ]
+        );
+    }
+}
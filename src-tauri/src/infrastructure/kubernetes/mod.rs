@@ -1,10 +1,20 @@
+pub mod autoscaling_repository;
+pub mod batch_repository;
+pub mod bulk_repository;
 pub mod client_manager;
 pub mod cluster_repository;
 pub mod config_repository;
+pub mod deployment_repository;
+pub mod discovery_service;
 pub mod editing_repository;
+pub mod finalizer_repository;
 pub mod gateway_repository;
 pub mod helpers;
 pub mod incident_repository;
 pub mod networking_repository;
+pub mod networkpolicy_repository;
+pub mod openapi_service;
 pub mod pod_repository;
+pub mod policy_repository;
+pub mod table_repository;
 pub mod workload_repository;
@@ -1,15 +1,105 @@
-use k8s_openapi::api::core::v1::Service;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use k8s_openapi::api::core::v1::{Pod, Service, ServicePort};
+use k8s_openapi::api::discovery::v1::EndpointSlice;
 use k8s_openapi::api::networking::v1::Ingress;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use kube::{api::ListParams, Api, Client};
 
 use crate::application::services::formatting::format_age;
 use crate::domain::entities::common::OwnerRefInfo;
 use crate::domain::entities::networking::*;
 use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::helpers::fetch_events_for;
+
+/// Backends of a single service, aggregated across all of its EndpointSlices.
+#[derive(Debug, Clone, Default)]
+pub struct ServiceBackends {
+    pub health: EndpointHealthInfo,
+    /// Names of ready backing pods (or the address when there is no targetRef).
+    pub ready_pods: Vec<String>,
+}
+
+const SERVICE_NAME_LABEL: &str = "kubernetes.io/service-name";
+
+pub async fn list_endpoint_slices(client: &Client, namespace: &str) -> Result<Vec<EndpointSlice>, DomainError> {
+    let api: Api<EndpointSlice> = Api::namespaced(client.clone(), namespace);
+    Ok(api.list(&ListParams::default().labels(SERVICE_NAME_LABEL)).await?.items)
+}
+
+/// Group EndpointSlices by owning service and count endpoints by condition, overall and
+/// per zone. Dual-stack services get one slice per address family, so endpoints are
+/// de-duplicated on their target pod.
+pub fn summarize_endpoint_slices(slices: &[EndpointSlice]) -> HashMap<String, ServiceBackends> {
+    let mut result: HashMap<String, ServiceBackends> = HashMap::new();
+    let mut zones: HashMap<String, BTreeMap<String, ZoneEndpointInfo>> = HashMap::new();
+    let mut seen: HashSet<(String, String)> = HashSet::new();
+
+    for slice in slices {
+        let svc_name = match slice.metadata.labels.as_ref().and_then(|l| l.get(SERVICE_NAME_LABEL)) {
+            Some(n) => n.clone(),
+            None => continue,
+        };
+        let backends = result.entry(svc_name.clone()).or_default();
+        let svc_zones = zones.entry(svc_name.clone()).or_default();
+
+        for ep in &slice.endpoints {
+            let target = ep
+                .target_ref
+                .as_ref()
+                .and_then(|tr| tr.name.clone())
+                .or_else(|| ep.addresses.first().cloned())
+                .unwrap_or_default();
+            if !seen.insert((svc_name.clone(), target.clone())) {
+                continue;
+            }
+
+            let conditions = ep.conditions.clone().unwrap_or_default();
+            // Per the API, a nil ready condition means ready, and nil serving follows ready.
+            let ready = conditions.ready.unwrap_or(true);
+            let serving = conditions.serving.unwrap_or(ready);
+            let terminating = conditions.terminating.unwrap_or(false);
+
+            let zone_name = ep.zone.clone().unwrap_or_else(|| "unknown".to_string());
+            let zone = svc_zones.entry(zone_name.clone()).or_insert_with(|| ZoneEndpointInfo {
+                zone: zone_name,
+                ready: 0,
+                serving: 0,
+                terminating: 0,
+            });
+
+            if ready {
+                backends.health.ready += 1;
+                zone.ready += 1;
+                backends.ready_pods.push(target);
+            }
+            if serving {
+                backends.health.serving += 1;
+                zone.serving += 1;
+            }
+            if terminating {
+                backends.health.terminating += 1;
+                zone.terminating += 1;
+            }
+        }
+    }
+
+    for (svc_name, svc_zones) in zones {
+        if let Some(backends) = result.get_mut(&svc_name) {
+            backends.health.zones = svc_zones.into_values().collect();
+        }
+    }
+    result
+}
 
 pub async fn list_services(client: &Client, namespace: &str) -> Result<Vec<ServiceInfo>, DomainError> {
     let api: Api<Service> = Api::namespaced(client.clone(), namespace);
     let list = api.list(&ListParams::default()).await?;
+    // EndpointSlices are best-effort: without them the list still renders, just without counts.
+    let mut backends = list_endpoint_slices(client, namespace)
+        .await
+        .map(|slices| summarize_endpoint_slices(&slices))
+        .unwrap_or_default();
 
     Ok(list
         .items
@@ -39,19 +129,255 @@ pub async fn list_services(client: &Client, namespace: &str) -> Result<Vec<Servi
                 external_ips
             };
 
+            let name = meta.name.unwrap_or_default();
+            let endpoints = backends.remove(&name).map(|b| b.health).unwrap_or_default();
+
             ServiceInfo {
-                name: meta.name.unwrap_or_default(),
+                name,
                 namespace: meta.namespace.unwrap_or_default(),
                 service_type: spec.type_.unwrap_or_else(|| "ClusterIP".to_string()),
                 cluster_ip: spec.cluster_ip.unwrap_or_else(|| "None".to_string()),
                 external_ip: external,
                 ports,
+                endpoints,
                 age: format_age(meta.creation_timestamp.as_ref()),
             }
         })
         .collect())
 }
 
+fn format_target_port(port: &ServicePort) -> String {
+    match &port.target_port {
+        Some(IntOrString::Int(p)) => p.to_string(),
+        Some(IntOrString::String(name)) => name.clone(),
+        None => port.port.to_string(),
+    }
+}
+
+/// Whether any container in `pod` declares the service port's target port.
+fn pod_exposes_port(pod: &Pod, port: &ServicePort) -> bool {
+    let protocol = port.protocol.as_deref().unwrap_or("TCP");
+    pod.spec
+        .as_ref()
+        .map(|s| {
+            s.containers.iter().flat_map(|c| c.ports.as_deref().unwrap_or_default()).any(|cp| {
+                let proto_ok = cp.protocol.as_deref().unwrap_or("TCP") == protocol;
+                let port_ok = match &port.target_port {
+                    Some(IntOrString::String(name)) => cp.name.as_deref() == Some(name.as_str()),
+                    Some(IntOrString::Int(p)) => cp.container_port == *p,
+                    None => cp.container_port == port.port,
+                };
+                proto_ok && port_ok
+            })
+        })
+        .unwrap_or(false)
+}
+
+/// Explain the usual reasons a service doesn't route traffic.
+fn service_diagnostics(
+    service_type: &str,
+    selector: &BTreeMap<String, String>,
+    ports: &[ServicePort],
+    pods: &[&Pod],
+    health: &EndpointHealthInfo,
+) -> Vec<String> {
+    let mut diagnostics = Vec::new();
+    if service_type == "ExternalName" {
+        return diagnostics;
+    }
+
+    if selector.is_empty() {
+        if health.ready == 0 {
+            diagnostics.push(
+                "Service has no selector and no ready endpoints; endpoints must be managed manually".to_string(),
+            );
+        }
+        return diagnostics;
+    }
+
+    let selector_str = selector.iter().map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<_>>().join(",");
+    if pods.is_empty() {
+        diagnostics.push(format!("Selector {} matches no pods in this namespace", selector_str));
+        return diagnostics;
+    }
+
+    for port in ports {
+        if !pods.iter().any(|p| pod_exposes_port(p, port)) {
+            let label = port.name.clone().unwrap_or_else(|| port.port.to_string());
+            let target = format_target_port(port);
+            match &port.target_port {
+                Some(IntOrString::String(_)) => diagnostics.push(format!(
+                    "Port {}: targetPort \"{}\" is not a named port on any selected pod; traffic will not be routed",
+                    label, target
+                )),
+                _ => diagnostics.push(format!(
+                    "Port {}: targetPort {} is not exposed by any container of the selected pods",
+                    label, target
+                )),
+            }
+        }
+    }
+
+    if health.ready == 0 {
+        if health.serving > 0 || health.terminating > 0 {
+            diagnostics.push("No ready endpoints: all backends are terminating".to_string());
+        } else {
+            diagnostics.push(format!(
+                "No ready endpoints: {} selected pod(s), none passing readiness",
+                pods.len()
+            ));
+        }
+    }
+    diagnostics
+}
+
+pub async fn get_service_detail(
+    client: &Client,
+    namespace: &str,
+    service_name: &str,
+) -> Result<ServiceDetailInfo, DomainError> {
+    let api: Api<Service> = Api::namespaced(client.clone(), namespace);
+    let svc = api.get(service_name).await?;
+
+    let meta = svc.metadata;
+    let spec = svc.spec.unwrap_or_default();
+    let status = svc.status.unwrap_or_default();
+
+    let name = meta.name.clone().unwrap_or_default();
+    let ns = meta.namespace.clone().unwrap_or_default();
+
+    let created = meta
+        .creation_timestamp
+        .as_ref()
+        .map(|t| t.0.to_rfc3339())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let service_type = spec.type_.clone().unwrap_or_else(|| "ClusterIP".to_string());
+    let selector = spec.selector.clone().unwrap_or_default();
+    let svc_ports = spec.ports.clone().unwrap_or_default();
+
+    let ports = svc_ports
+        .iter()
+        .map(|p| ServicePortInfo {
+            name: p.name.clone().unwrap_or_default(),
+            protocol: p.protocol.clone().unwrap_or_else(|| "TCP".to_string()),
+            port: p.port,
+            target_port: format_target_port(p),
+            node_port: p.node_port,
+            app_protocol: p.app_protocol.clone(),
+        })
+        .collect();
+
+    // A service without a selector selects nothing (endpoints are managed externally)
+    let pod_list = if selector.is_empty() {
+        vec![]
+    } else {
+        let pod_api: Api<Pod> = Api::namespaced(client.clone(), namespace);
+        pod_api.list(&ListParams::default()).await?.items
+    };
+    let selected: Vec<&Pod> = pod_list
+        .iter()
+        .filter(|p| {
+            let labels = p.metadata.labels.as_ref();
+            selector.iter().all(|(k, v)| labels.and_then(|l| l.get(k)) == Some(v))
+        })
+        .collect();
+
+    let matching_pods = selected
+        .iter()
+        .map(|p| {
+            let pod_status = p.status.clone().unwrap_or_default();
+            let ready = pod_status
+                .conditions
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .any(|c| c.type_ == "Ready" && c.status == "True");
+            ServicePodInfo {
+                name: p.metadata.name.clone().unwrap_or_default(),
+                phase: pod_status.phase.unwrap_or_else(|| "Unknown".to_string()),
+                ready,
+                ip: pod_status.pod_ip.unwrap_or_default(),
+                node: p.spec.as_ref().and_then(|s| s.node_name.clone()).unwrap_or_default(),
+            }
+        })
+        .collect();
+
+    let slice_api: Api<EndpointSlice> = Api::namespaced(client.clone(), namespace);
+    let slices = slice_api
+        .list(&ListParams::default().labels(&format!("{}={}", SERVICE_NAME_LABEL, name)))
+        .await?
+        .items;
+    let endpoint_health = summarize_endpoint_slices(&slices)
+        .remove(&name)
+        .map(|b| b.health)
+        .unwrap_or_default();
+    let endpoints = slices
+        .iter()
+        .flat_map(|s| s.endpoints.iter())
+        .map(|ep| {
+            let conditions = ep.conditions.clone().unwrap_or_default();
+            let ready = conditions.ready.unwrap_or(true);
+            ServiceEndpointInfo {
+                addresses: ep.addresses.clone(),
+                target: ep
+                    .target_ref
+                    .as_ref()
+                    .map(|tr| format!("{}/{}", tr.kind.clone().unwrap_or_default(), tr.name.clone().unwrap_or_default()))
+                    .unwrap_or_default(),
+                node: ep.node_name.clone().unwrap_or_default(),
+                zone: ep.zone.clone().unwrap_or_default(),
+                ready,
+                serving: conditions.serving.unwrap_or(ready),
+                terminating: conditions.terminating.unwrap_or(false),
+            }
+        })
+        .collect();
+
+    let diagnostics = service_diagnostics(&service_type, &selector, &svc_ports, &selected, &endpoint_health);
+
+    let load_balancer_ingress = status
+        .load_balancer
+        .and_then(|lb| lb.ingress)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|i| i.ip.or(i.hostname))
+        .collect();
+
+    let events = fetch_events_for(client, namespace, &name, "Service").await;
+
+    Ok(ServiceDetailInfo {
+        name,
+        namespace: ns,
+        created,
+        labels: meta.labels.unwrap_or_default(),
+        annotations: meta.annotations.unwrap_or_default(),
+        service_type,
+        cluster_ips: spec
+            .cluster_ips
+            .clone()
+            .unwrap_or_else(|| spec.cluster_ip.clone().into_iter().collect()),
+        external_ips: spec.external_ips.clone().unwrap_or_default(),
+        load_balancer_ingress,
+        external_name: spec.external_name.clone(),
+        ports,
+        selector,
+        session_affinity: spec.session_affinity.clone().unwrap_or_else(|| "None".to_string()),
+        session_affinity_timeout: spec
+            .session_affinity_config
+            .as_ref()
+            .and_then(|c| c.client_ip.as_ref())
+            .and_then(|c| c.timeout_seconds),
+        external_traffic_policy: spec.external_traffic_policy.clone(),
+        internal_traffic_policy: spec.internal_traffic_policy.clone(),
+        matching_pods,
+        endpoints,
+        endpoint_health,
+        diagnostics,
+        events,
+    })
+}
+
 pub async fn list_ingresses(client: &Client, namespace: &str) -> Result<Vec<IngressInfo>, DomainError> {
     let api: Api<Ingress> = Api::namespaced(client.clone(), namespace);
     let list = api.list(&ListParams::default()).await?;
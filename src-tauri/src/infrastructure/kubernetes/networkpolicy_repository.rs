@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+
+use k8s_openapi::api::core::v1::{Namespace, Pod};
+use k8s_openapi::api::networking::v1::{NetworkPolicy, NetworkPolicyPeer, NetworkPolicyPort};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::{api::ListParams, Api, Client};
+
+use crate::application::services::formatting::format_age;
+use crate::application::services::labels::label_selector_matches;
+use crate::application::services::network_policy_analyzer::{self, effective_policy_types, PodEndpoint};
+use crate::domain::entities::networking::*;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::helpers::format_label_selector;
+
+fn format_peer(peer: &NetworkPolicyPeer) -> String {
+    if let Some(block) = &peer.ip_block {
+        let except = block.except.as_deref().unwrap_or_default();
+        return if except.is_empty() {
+            format!("ipBlock {}", block.cidr)
+        } else {
+            format!("ipBlock {} except {}", block.cidr, except.join(", "))
+        };
+    }
+    match (&peer.namespace_selector, &peer.pod_selector) {
+        (Some(ns), Some(pod)) => format!(
+            "namespaces [{}] pods [{}]",
+            format_label_selector(ns),
+            format_label_selector(pod)
+        ),
+        (Some(ns), None) => format!("namespaces [{}]", format_label_selector(ns)),
+        (None, Some(pod)) => format!("pods [{}]", format_label_selector(pod)),
+        (None, None) => "<all>".to_string(),
+    }
+}
+
+fn format_port(port: &NetworkPolicyPort) -> String {
+    let protocol = port.protocol.clone().unwrap_or_else(|| "TCP".to_string());
+    match (&port.port, port.end_port) {
+        (Some(IntOrString::Int(p)), Some(end)) => format!("{}-{}/{}", p, end, protocol),
+        (Some(IntOrString::Int(p)), None) => format!("{}/{}", p, protocol),
+        (Some(IntOrString::String(name)), _) => format!("{}/{}", name, protocol),
+        (None, _) => format!("<all>/{}", protocol),
+    }
+}
+
+fn build_rule(peers: Option<&Vec<NetworkPolicyPeer>>, ports: Option<&Vec<NetworkPolicyPort>>) -> NetworkPolicyRuleInfo {
+    let peers: Vec<String> = peers.map(|ps| ps.iter().map(format_peer).collect()).unwrap_or_default();
+    let ports: Vec<String> = ports.map(|ps| ps.iter().map(format_port).collect()).unwrap_or_default();
+    NetworkPolicyRuleInfo {
+        peers: if peers.is_empty() { vec!["<all>".to_string()] } else { peers },
+        ports: if ports.is_empty() { vec!["<all>".to_string()] } else { ports },
+    }
+}
+
+pub async fn list_network_policies(client: &Client, namespace: &str) -> Result<Vec<NetworkPolicyInfo>, DomainError> {
+    let api: Api<NetworkPolicy> = Api::namespaced(client.clone(), namespace);
+    let list = api.list(&ListParams::default()).await?;
+
+    Ok(list
+        .items
+        .into_iter()
+        .map(|np| {
+            let policy_types = effective_policy_types(&np);
+            let meta = np.metadata;
+            let spec = np.spec.unwrap_or_default();
+
+            NetworkPolicyInfo {
+                name: meta.name.unwrap_or_default(),
+                namespace: meta.namespace.unwrap_or_default(),
+                pod_selector: format_label_selector(&spec.pod_selector),
+                policy_types,
+                ingress_rules: spec.ingress.map(|r| r.len() as i32).unwrap_or(0),
+                egress_rules: spec.egress.map(|r| r.len() as i32).unwrap_or(0),
+                age: format_age(meta.creation_timestamp.as_ref()),
+            }
+        })
+        .collect())
+}
+
+pub async fn get_network_policy_detail(
+    client: &Client,
+    namespace: &str,
+    policy_name: &str,
+) -> Result<NetworkPolicyDetailInfo, DomainError> {
+    let api: Api<NetworkPolicy> = Api::namespaced(client.clone(), namespace);
+    let np = api.get(policy_name).await?;
+
+    let policy_types = effective_policy_types(&np);
+    let meta = np.metadata;
+    let spec = np.spec.unwrap_or_default();
+
+    let created = meta
+        .creation_timestamp
+        .as_ref()
+        .map(|t| t.0.to_rfc3339())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let ingress = spec
+        .ingress
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .map(|r| build_rule(r.from.as_ref(), r.ports.as_ref()))
+        .collect();
+    let egress = spec
+        .egress
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .map(|r| build_rule(r.to.as_ref(), r.ports.as_ref()))
+        .collect();
+
+    let pod_api: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let selected_pods = pod_api
+        .list(&ListParams::default())
+        .await
+        .map(|pods| {
+            pods.items
+                .into_iter()
+                .filter(|p| {
+                    let labels = p.metadata.labels.clone().unwrap_or_default();
+                    label_selector_matches(&spec.pod_selector, &labels)
+                })
+                .filter_map(|p| p.metadata.name)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(NetworkPolicyDetailInfo {
+        name: meta.name.unwrap_or_default(),
+        namespace: meta.namespace.unwrap_or_default(),
+        created,
+        labels: meta.labels.unwrap_or_default(),
+        annotations: meta.annotations.unwrap_or_default(),
+        pod_selector: format_label_selector(&spec.pod_selector),
+        policy_types,
+        ingress,
+        egress,
+        selected_pods,
+    })
+}
+
+/// Namespace labels used for namespaceSelector matching. If the namespace can't be read
+/// (e.g. no cluster-scoped RBAC) fall back to the label the API server always sets.
+async fn fetch_namespace_labels(client: &Client, namespace: &str) -> BTreeMap<String, String> {
+    let api: Api<Namespace> = Api::all(client.clone());
+    let mut labels = api
+        .get(namespace)
+        .await
+        .ok()
+        .and_then(|ns| ns.metadata.labels)
+        .unwrap_or_default();
+    labels
+        .entry("kubernetes.io/metadata.name".to_string())
+        .or_insert_with(|| namespace.to_string());
+    labels
+}
+
+async fn fetch_endpoint(client: &Client, namespace: &str, pod_name: &str) -> Result<PodEndpoint, DomainError> {
+    let api: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let pod = api.get(pod_name).await?;
+
+    let named_ports = pod
+        .spec
+        .as_ref()
+        .map(|s| {
+            s.containers
+                .iter()
+                .flat_map(|c| c.ports.clone().unwrap_or_default())
+                .filter_map(|p| {
+                    p.name.map(|n| (n, p.container_port, p.protocol.unwrap_or_else(|| "TCP".to_string())))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(PodEndpoint {
+        name: pod_name.to_string(),
+        namespace: namespace.to_string(),
+        labels: pod.metadata.labels.unwrap_or_default(),
+        namespace_labels: fetch_namespace_labels(client, namespace).await,
+        ip: pod.status.and_then(|s| s.pod_ip),
+        named_ports,
+    })
+}
+
+/// Decide whether `source_pod` may connect to `dest_pod` on `port`/`protocol`, evaluating
+/// egress policies in the source namespace and ingress policies in the destination namespace.
+pub async fn analyze_reachability(
+    client: &Client,
+    source_namespace: &str,
+    source_pod: &str,
+    dest_namespace: &str,
+    dest_pod: &str,
+    port: i32,
+    protocol: &str,
+) -> Result<ReachabilityResult, DomainError> {
+    let source = fetch_endpoint(client, source_namespace, source_pod).await?;
+    let destination = fetch_endpoint(client, dest_namespace, dest_pod).await?;
+
+    let lp = ListParams::default();
+    let mut policies: Vec<NetworkPolicy> = Api::namespaced(client.clone(), source_namespace)
+        .list(&lp)
+        .await?
+        .items;
+    if dest_namespace != source_namespace {
+        let dest_policies: Api<NetworkPolicy> = Api::namespaced(client.clone(), dest_namespace);
+        policies.extend(dest_policies.list(&lp).await?.items);
+    }
+
+    let protocol = protocol.to_uppercase();
+    let (egress, ingress) = network_policy_analyzer::evaluate(&policies, &source, &destination, port, &protocol);
+    let allowed = egress.allowed && ingress.allowed;
+
+    let explanation = if allowed {
+        format!("Allowed. Egress: {} Ingress: {}", egress.reason, ingress.reason)
+    } else if !egress.allowed {
+        format!("Blocked by egress policy. {}", egress.reason)
+    } else {
+        format!("Blocked by ingress policy. {}", ingress.reason)
+    };
+
+    Ok(ReachabilityResult {
+        allowed,
+        source: format!("{}/{}", source_namespace, source_pod),
+        destination: format!("{}/{}", dest_namespace, dest_pod),
+        port,
+        protocol,
+        egress,
+        ingress,
+        explanation,
+    })
+}
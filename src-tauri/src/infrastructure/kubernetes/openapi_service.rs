@@ -0,0 +1,87 @@
+use kube::{api::GetParams, core::Request, Client};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::domain::errors::DomainError;
+
+/// Documents keyed by (context, group-version path); the path index is stored under "".
+type DocumentCache = HashMap<(String, String), Arc<Value>>;
+
+/// Per-context cache of the API server's OpenAPI v3 documents, one per group-version.
+/// CRD schemas are published here too once the CRD is established.
+pub struct OpenApiService {
+    cache: Arc<Mutex<DocumentCache>>,
+}
+
+impl Default for OpenApiService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// GET a server-relative path. `Request::get` appends the name verbatim, which lets the
+/// `?hash=` query from `serverRelativeURL` pass through untouched.
+async fn fetch_json(client: &Client, path: &str) -> Result<Value, DomainError> {
+    let (base, name) = path.rsplit_once('/').unwrap_or(("", path));
+    let req = Request::new(base)
+        .get(name, &GetParams::default())
+        .map_err(|e| DomainError::KubernetesApi(e.to_string()))?;
+    Ok(client.request::<Value>(req).await?)
+}
+
+impl OpenApiService {
+    pub fn new() -> Self {
+        Self {
+            cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    async fn cached(&self, client: &Client, context: &str, key: &str, path: &str) -> Result<Arc<Value>, DomainError> {
+        let cache_key = (context.to_string(), key.to_string());
+        if let Some(doc) = self.cache.lock().await.get(&cache_key) {
+            return Ok(doc.clone());
+        }
+        let doc = Arc::new(fetch_json(client, path).await?);
+        self.cache.lock().await.insert(cache_key, doc.clone());
+        Ok(doc)
+    }
+
+    /// The OpenAPI v3 document for `api_version` (e.g. "apps/v1" or "v1").
+    pub async fn group_version_doc(
+        &self,
+        client: &Client,
+        context: &str,
+        api_version: &str,
+    ) -> Result<Arc<Value>, DomainError> {
+        let index = self.cached(client, context, "", "/openapi/v3").await?;
+        let key = if api_version.contains('/') {
+            format!("apis/{}", api_version)
+        } else {
+            format!("api/{}", api_version)
+        };
+        let url = index["paths"][&key]["serverRelativeURL"]
+            .as_str()
+            .ok_or_else(|| DomainError::NotFound(format!("No OpenAPI v3 document for {}", api_version)))?
+            .to_string();
+        self.cached(client, context, &key, &url).await
+    }
+
+    pub async fn invalidate(&self, context: &str) {
+        self.cache.lock().await.retain(|(ctx, _), _| ctx != context);
+    }
+}
+
+/// Find the schema for `kind` in a group-version document via `x-kubernetes-group-version-kind`.
+pub fn find_kind_schema<'a>(doc: &'a Value, api_version: &str, kind: &str) -> Option<&'a Value> {
+    let (group, version) = api_version.split_once('/').unwrap_or(("", api_version));
+    doc["components"]["schemas"].as_object()?.values().find(|schema| {
+        schema["x-kubernetes-group-version-kind"]
+            .as_array()
+            .is_some_and(|gvks| {
+                gvks.iter()
+                    .any(|gvk| gvk["group"] == group && gvk["version"] == version && gvk["kind"] == kind)
+            })
+    })
+}
@@ -0,0 +1,383 @@
+use k8s_openapi::api::core::v1::{LimitRange, Pod, ResourceQuota};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::{api::ListParams, Api, Client};
+use std::collections::BTreeMap;
+
+use crate::application::services::formatting::{format_age, parse_quantity};
+use crate::application::services::labels::label_selector_matches;
+use crate::domain::entities::common::ConditionInfo;
+use crate::domain::entities::policy::*;
+use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::helpers::fetch_events_for;
+
+fn format_int_or_string(val: Option<&IntOrString>) -> String {
+    match val {
+        Some(IntOrString::Int(i)) => i.to_string(),
+        Some(IntOrString::String(s)) => s.clone(),
+        None => "N/A".to_string(),
+    }
+}
+
+/// Build used-vs-hard rows for a quota, preferring the enforced limits in `status.hard`.
+fn build_quota_usage(quota: &ResourceQuota) -> Vec<QuotaUsageInfo> {
+    let status = quota.status.clone().unwrap_or_default();
+    let hard = status
+        .hard
+        .or_else(|| quota.spec.as_ref().and_then(|s| s.hard.clone()))
+        .unwrap_or_default();
+    let used = status.used.unwrap_or_default();
+
+    hard.into_iter()
+        .map(|(resource, hard_q)| {
+            let used_q = used.get(&resource).map(|q| q.0.clone()).unwrap_or_else(|| "0".to_string());
+            let percent = match (parse_quantity(&used_q), parse_quantity(&hard_q.0)) {
+                (Some(u), Some(h)) if h > 0.0 => Some((u / h * 1000.0).round() / 10.0),
+                _ => None,
+            };
+            QuotaUsageInfo {
+                resource,
+                used: used_q,
+                hard: hard_q.0,
+                percent,
+            }
+        })
+        .collect()
+}
+
+fn quantity_map_value(map: &Option<BTreeMap<String, Quantity>>, resource: &str) -> String {
+    map.as_ref()
+        .and_then(|m| m.get(resource))
+        .map(|q| q.0.clone())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+pub async fn list_pdbs(client: &Client, namespace: &str) -> Result<Vec<PdbInfo>, DomainError> {
+    let api: Api<PodDisruptionBudget> = Api::namespaced(client.clone(), namespace);
+    let list = api.list(&ListParams::default()).await?;
+
+    Ok(list
+        .items
+        .into_iter()
+        .map(|pdb| {
+            let meta = pdb.metadata;
+            let spec = pdb.spec.unwrap_or_default();
+            let status = pdb.status.unwrap_or_default();
+
+            PdbInfo {
+                name: meta.name.unwrap_or_default(),
+                namespace: meta.namespace.unwrap_or_default(),
+                min_available: format_int_or_string(spec.min_available.as_ref()),
+                max_unavailable: format_int_or_string(spec.max_unavailable.as_ref()),
+                allowed_disruptions: status.disruptions_allowed,
+                current_healthy: status.current_healthy,
+                desired_healthy: status.desired_healthy,
+                expected_pods: status.expected_pods,
+                age: format_age(meta.creation_timestamp.as_ref()),
+            }
+        })
+        .collect())
+}
+
+pub async fn get_pdb_detail(
+    client: &Client,
+    namespace: &str,
+    pdb_name: &str,
+) -> Result<PdbDetailInfo, DomainError> {
+    let api: Api<PodDisruptionBudget> = Api::namespaced(client.clone(), namespace);
+    let pdb = api.get(pdb_name).await?;
+
+    let meta = pdb.metadata;
+    let spec = pdb.spec.unwrap_or_default();
+    let status = pdb.status.unwrap_or_default();
+
+    let name = meta.name.clone().unwrap_or_default();
+    let ns = meta.namespace.clone().unwrap_or_default();
+
+    let created = meta
+        .creation_timestamp
+        .as_ref()
+        .map(|t| t.0.to_rfc3339())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let mut selector: Vec<String> = Vec::new();
+    if let Some(sel) = &spec.selector {
+        for (k, v) in sel.match_labels.clone().unwrap_or_default() {
+            selector.push(format!("{}={}", k, v));
+        }
+        for req in sel.match_expressions.clone().unwrap_or_default() {
+            let values = req.values.unwrap_or_default().join(",");
+            selector.push(format!("{} {} ({})", req.key, req.operator, values));
+        }
+    }
+
+    // A PDB with no selector matches no pods (policy/v1 semantics)
+    let disrupted = status.disrupted_pods.clone().unwrap_or_default();
+    let matching_pods: Vec<PdbPodInfo> = match &spec.selector {
+        Some(sel) => {
+            let pod_api: Api<Pod> = Api::namespaced(client.clone(), namespace);
+            let pods = pod_api.list(&ListParams::default()).await?;
+            pods.items
+                .into_iter()
+                .filter(|p| label_selector_matches(sel, p.metadata.labels.as_ref().unwrap_or(&BTreeMap::new())))
+                .map(|p| {
+                    let pod_name = p.metadata.name.unwrap_or_default();
+                    let pod_status = p.status.unwrap_or_default();
+                    let ready = pod_status
+                        .conditions
+                        .as_deref()
+                        .unwrap_or_default()
+                        .iter()
+                        .any(|c| c.type_ == "Ready" && c.status == "True");
+                    PdbPodInfo {
+                        disrupted: disrupted.contains_key(&pod_name),
+                        name: pod_name,
+                        phase: pod_status.phase.unwrap_or_else(|| "Unknown".to_string()),
+                        ready,
+                    }
+                })
+                .collect()
+        }
+        None => vec![],
+    };
+
+    let conditions: Vec<ConditionInfo> = status
+        .conditions
+        .unwrap_or_default()
+        .into_iter()
+        .map(|c| ConditionInfo {
+            condition_type: c.type_,
+            status: c.status,
+            reason: c.reason,
+            message: c.message,
+            last_transition: format_age(Some(&c.last_transition_time)),
+        })
+        .collect();
+
+    let events = fetch_events_for(client, namespace, &name, "PodDisruptionBudget").await;
+
+    Ok(PdbDetailInfo {
+        name,
+        namespace: ns,
+        created,
+        labels: meta.labels.unwrap_or_default(),
+        annotations: meta.annotations.unwrap_or_default(),
+        selector,
+        min_available: format_int_or_string(spec.min_available.as_ref()),
+        max_unavailable: format_int_or_string(spec.max_unavailable.as_ref()),
+        unhealthy_pod_eviction_policy: spec
+            .unhealthy_pod_eviction_policy
+            .unwrap_or_else(|| "IfHealthyBudget".to_string()),
+        allowed_disruptions: status.disruptions_allowed,
+        current_healthy: status.current_healthy,
+        desired_healthy: status.desired_healthy,
+        expected_pods: status.expected_pods,
+        matching_pods,
+        conditions,
+        events,
+    })
+}
+
+pub async fn list_resource_quotas(client: &Client, namespace: &str) -> Result<Vec<ResourceQuotaInfo>, DomainError> {
+    let api: Api<ResourceQuota> = Api::namespaced(client.clone(), namespace);
+    let list = api.list(&ListParams::default()).await?;
+
+    Ok(list
+        .items
+        .iter()
+        .map(|quota| ResourceQuotaInfo {
+            name: quota.metadata.name.clone().unwrap_or_default(),
+            namespace: quota.metadata.namespace.clone().unwrap_or_default(),
+            resources: build_quota_usage(quota),
+            age: format_age(quota.metadata.creation_timestamp.as_ref()),
+        })
+        .collect())
+}
+
+pub async fn get_resource_quota_detail(
+    client: &Client,
+    namespace: &str,
+    quota_name: &str,
+) -> Result<ResourceQuotaDetailInfo, DomainError> {
+    let api: Api<ResourceQuota> = Api::namespaced(client.clone(), namespace);
+    let quota = api.get(quota_name).await?;
+
+    let resources = build_quota_usage(&quota);
+    let meta = quota.metadata;
+    let name = meta.name.clone().unwrap_or_default();
+
+    let created = meta
+        .creation_timestamp
+        .as_ref()
+        .map(|t| t.0.to_rfc3339())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let scopes = quota
+        .spec
+        .and_then(|s| s.scopes)
+        .unwrap_or_default();
+
+    let events = fetch_events_for(client, namespace, &name, "ResourceQuota").await;
+
+    Ok(ResourceQuotaDetailInfo {
+        name,
+        namespace: meta.namespace.unwrap_or_default(),
+        created,
+        labels: meta.labels.unwrap_or_default(),
+        annotations: meta.annotations.unwrap_or_default(),
+        scopes,
+        resources,
+        events,
+    })
+}
+
+pub async fn list_limit_ranges(client: &Client, namespace: &str) -> Result<Vec<LimitRangeInfo>, DomainError> {
+    let api: Api<LimitRange> = Api::namespaced(client.clone(), namespace);
+    let list = api.list(&ListParams::default()).await?;
+
+    Ok(list
+        .items
+        .into_iter()
+        .map(|lr| {
+            let meta = lr.metadata;
+            let mut limit_types: Vec<String> = lr
+                .spec
+                .map(|s| s.limits.into_iter().map(|l| l.type_).collect())
+                .unwrap_or_default();
+            limit_types.sort();
+            limit_types.dedup();
+
+            LimitRangeInfo {
+                name: meta.name.unwrap_or_default(),
+                namespace: meta.namespace.unwrap_or_default(),
+                limit_types,
+                age: format_age(meta.creation_timestamp.as_ref()),
+            }
+        })
+        .collect())
+}
+
+pub async fn get_limit_range_detail(
+    client: &Client,
+    namespace: &str,
+    limit_range_name: &str,
+) -> Result<LimitRangeDetailInfo, DomainError> {
+    let api: Api<LimitRange> = Api::namespaced(client.clone(), namespace);
+    let lr = api.get(limit_range_name).await?;
+
+    let meta = lr.metadata;
+    let created = meta
+        .creation_timestamp
+        .as_ref()
+        .map(|t| t.0.to_rfc3339())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    // Flatten each LimitRangeItem into one row per resource, like `kubectl describe limitrange`
+    let mut limits: Vec<LimitRangeItemInfo> = Vec::new();
+    for item in lr.spec.map(|s| s.limits).unwrap_or_default() {
+        let mut resources: Vec<String> = Vec::new();
+        for map in [&item.min, &item.max, &item.default, &item.default_request, &item.max_limit_request_ratio]
+            .into_iter()
+            .flatten()
+        {
+            for key in map.keys() {
+                if !resources.contains(key) {
+                    resources.push(key.clone());
+                }
+            }
+        }
+        resources.sort();
+
+        for resource in resources {
+            limits.push(LimitRangeItemInfo {
+                limit_type: item.type_.clone(),
+                min: quantity_map_value(&item.min, &resource),
+                max: quantity_map_value(&item.max, &resource),
+                default_request: quantity_map_value(&item.default_request, &resource),
+                default_limit: quantity_map_value(&item.default, &resource),
+                max_limit_request_ratio: quantity_map_value(&item.max_limit_request_ratio, &resource),
+                resource,
+            });
+        }
+    }
+
+    Ok(LimitRangeDetailInfo {
+        name: meta.name.unwrap_or_default(),
+        namespace: meta.namespace.unwrap_or_default(),
+        created,
+        labels: meta.labels.unwrap_or_default(),
+        annotations: meta.annotations.unwrap_or_default(),
+        limits,
+    })
+}
+
+/// Fetch the PDBs and quotas needed to flag blocked workloads, once per listing.
+/// Failures (e.g. RBAC) degrade to empty lists so workload listings keep working.
+pub async fn load_workload_policies(
+    client: &Client,
+    namespace: &str,
+) -> (Vec<PodDisruptionBudget>, Vec<ResourceQuota>) {
+    let pdb_api: Api<PodDisruptionBudget> = Api::namespaced(client.clone(), namespace);
+    let quota_api: Api<ResourceQuota> = Api::namespaced(client.clone(), namespace);
+    let lp = ListParams::default();
+    let (pdbs, quotas) = tokio::join!(pdb_api.list(&lp), quota_api.list(&lp));
+    (
+        pdbs.map(|l| l.items).unwrap_or_default(),
+        quotas.map(|l| l.items).unwrap_or_default(),
+    )
+}
+
+/// Explain why a workload with the given pod template labels is held back:
+/// a matching PDB that allows no disruptions, or a quota preventing pod creation.
+/// `failure_message` is the workload's ReplicaFailure condition message, if any.
+pub fn find_workload_blockers(
+    pdbs: &[PodDisruptionBudget],
+    quotas: &[ResourceQuota],
+    pod_labels: &BTreeMap<String, String>,
+    ready: i32,
+    desired: i32,
+    failure_message: Option<&str>,
+) -> Vec<String> {
+    let mut blockers = Vec::new();
+
+    for pdb in pdbs {
+        let Some(selector) = pdb.spec.as_ref().and_then(|s| s.selector.as_ref()) else {
+            continue;
+        };
+        let status = pdb.status.clone().unwrap_or_default();
+        if status.expected_pods > 0
+            && status.disruptions_allowed == 0
+            && label_selector_matches(selector, pod_labels)
+        {
+            blockers.push(format!(
+                "PodDisruptionBudget {}: 0 disruptions allowed ({}/{} healthy)",
+                pdb.metadata.name.clone().unwrap_or_default(),
+                status.current_healthy,
+                status.desired_healthy,
+            ));
+        }
+    }
+
+    match failure_message {
+        Some(msg) if msg.contains("exceeded quota") => {
+            blockers.push(format!("ResourceQuota: {}", msg));
+        }
+        _ if ready < desired => {
+            for quota in quotas {
+                let quota_name = quota.metadata.name.clone().unwrap_or_default();
+                for usage in build_quota_usage(quota) {
+                    if usage.percent.map(|p| p >= 100.0).unwrap_or(false) {
+                        blockers.push(format!(
+                            "ResourceQuota {}: {} exhausted ({}/{})",
+                            quota_name, usage.resource, usage.used, usage.hard
+                        ));
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    blockers
+}
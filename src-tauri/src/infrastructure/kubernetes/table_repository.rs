@@ -0,0 +1,120 @@
+use kube::{
+    api::{DynamicObject, ListParams},
+    core::Request,
+    discovery::ApiResource,
+    Client, Resource,
+};
+use serde_json::Value;
+
+use crate::domain::entities::common::{ResourceTableInfo, TableColumnInfo, TableRowInfo};
+use crate::domain::errors::DomainError;
+
+/// Ask for the server-side Table rendering, falling back to plain JSON for servers
+/// (mostly aggregated APIs) that don't implement it.
+const TABLE_ACCEPT: &str = "application/json;as=Table;v=v1;g=meta.k8s.io,application/json;as=Table;v=v1beta1;g=meta.k8s.io,application/json";
+
+fn parse_columns(table: &Value) -> Vec<TableColumnInfo> {
+    table["columnDefinitions"]
+        .as_array()
+        .map(|cols| {
+            cols.iter()
+                .map(|c| TableColumnInfo {
+                    name: c["name"].as_str().unwrap_or_default().to_string(),
+                    column_type: c["type"].as_str().unwrap_or("string").to_string(),
+                    format: c["format"].as_str().unwrap_or_default().to_string(),
+                    description: c["description"].as_str().unwrap_or_default().to_string(),
+                    priority: c["priority"].as_i64().unwrap_or(0) as i32,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn parse_rows(table: &Value) -> Vec<TableRowInfo> {
+    table["rows"]
+        .as_array()
+        .map(|rows| {
+            rows.iter()
+                .map(|r| {
+                    // includeObject defaults to Metadata, so each row carries a PartialObjectMetadata
+                    let meta = &r["object"]["metadata"];
+                    TableRowInfo {
+                        name: meta["name"].as_str().unwrap_or_default().to_string(),
+                        namespace: meta["namespace"].as_str().unwrap_or_default().to_string(),
+                        cells: r["cells"].as_array().cloned().unwrap_or_default(),
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// A plain list came back instead of a Table; render the same Name/Age columns kubectl
+/// falls back to.
+fn table_from_list(list: &Value) -> (Vec<TableColumnInfo>, Vec<TableRowInfo>) {
+    let column = |name: &str, column_type: &str, format: &str| TableColumnInfo {
+        name: name.to_string(),
+        column_type: column_type.to_string(),
+        format: format.to_string(),
+        description: String::new(),
+        priority: 0,
+    };
+    let columns = vec![column("Name", "string", "name"), column("Created At", "date", "")];
+    let rows = list["items"]
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .map(|item| {
+                    let meta = &item["metadata"];
+                    TableRowInfo {
+                        name: meta["name"].as_str().unwrap_or_default().to_string(),
+                        namespace: meta["namespace"].as_str().unwrap_or_default().to_string(),
+                        cells: vec![meta["name"].clone(), meta["creationTimestamp"].clone()],
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    (columns, rows)
+}
+
+/// List any served resource type with the columns `kubectl get` would show, as computed by
+/// the API server (including CRD additionalPrinterColumns).
+pub async fn list_resource_table(
+    client: &Client,
+    namespace: Option<&str>,
+    ar: &ApiResource,
+    cluster_scoped: bool,
+    label_selector: Option<&str>,
+) -> Result<ResourceTableInfo, DomainError> {
+    let ns = if cluster_scoped { None } else { namespace };
+    let url = DynamicObject::url_path(ar, ns);
+
+    let mut lp = ListParams::default();
+    if let Some(selector) = label_selector.filter(|s| !s.is_empty()) {
+        lp = lp.labels(selector);
+    }
+    let mut req = Request::new(url)
+        .list(&lp)
+        .map_err(|e| DomainError::KubernetesApi(e.to_string()))?;
+    let accept = TABLE_ACCEPT
+        .parse()
+        .map_err(|e| DomainError::Configuration(format!("Invalid Accept header: {}", e)))?;
+    req.headers_mut().insert("accept", accept);
+    let resp: Value = client.request(req).await?;
+
+    let (columns, rows) = if resp["kind"] == "Table" {
+        (parse_columns(&resp), parse_rows(&resp))
+    } else {
+        table_from_list(&resp)
+    };
+
+    Ok(ResourceTableInfo {
+        kind: ar.kind.clone(),
+        api_version: ar.api_version.clone(),
+        namespaced: !cluster_scoped,
+        columns,
+        rows,
+    })
+}
@@ -1,15 +1,23 @@
 use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
 use k8s_openapi::api::batch::v1::{CronJob, Job};
-use k8s_openapi::api::core::v1::ReplicationController;
-use kube::{api::ListParams, Api, Client};
+use k8s_openapi::api::core::v1::{Container, ReplicationController};
+use kube::{
+    api::{ListParams, Patch, PatchParams},
+    Api, Client, Resource,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
 
+use crate::application::services::diff::{diff_lines, has_changes};
 use crate::application::services::formatting::format_age;
 use crate::domain::entities::workload::*;
 use crate::domain::errors::DomainError;
+use crate::infrastructure::kubernetes::policy_repository::{find_workload_blockers, load_workload_policies};
 
 pub async fn list_deployments(client: &Client, namespace: &str) -> Result<Vec<DeploymentInfo>, DomainError> {
     let api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
     let list = api.list(&ListParams::default()).await?;
+    let (pdbs, quotas) = load_workload_policies(client, namespace).await;
 
     Ok(list
         .items
@@ -22,6 +30,23 @@ pub async fn list_deployments(client: &Client, namespace: &str) -> Result<Vec<De
             let replicas = spec.replicas.unwrap_or(0);
             let ready = status.ready_replicas.unwrap_or(0);
 
+            let replica_failure = status
+                .conditions
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .find(|c| c.type_ == "ReplicaFailure" && c.status == "True")
+                .and_then(|c| c.message.clone());
+            let pod_labels = spec.template.metadata.and_then(|m| m.labels).unwrap_or_default();
+            let blocked_by = find_workload_blockers(
+                &pdbs,
+                &quotas,
+                &pod_labels,
+                ready,
+                replicas,
+                replica_failure.as_deref(),
+            );
+
             DeploymentInfo {
                 name: meta.name.unwrap_or_default(),
                 namespace: meta.namespace.unwrap_or_default(),
@@ -29,6 +54,7 @@ pub async fn list_deployments(client: &Client, namespace: &str) -> Result<Vec<De
                 up_to_date: status.updated_replicas.unwrap_or(0),
                 available: status.available_replicas.unwrap_or(0),
                 age: format_age(meta.creation_timestamp.as_ref()),
+                blocked_by,
             }
         })
         .collect())
@@ -61,6 +87,7 @@ pub async fn list_daemonsets(client: &Client, namespace: &str) -> Result<Vec<Dae
 pub async fn list_statefulsets(client: &Client, namespace: &str) -> Result<Vec<StatefulSetInfo>, DomainError> {
     let api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
     let list = api.list(&ListParams::default()).await?;
+    let (pdbs, quotas) = load_workload_policies(client, namespace).await;
 
     Ok(list
         .items
@@ -73,16 +100,67 @@ pub async fn list_statefulsets(client: &Client, namespace: &str) -> Result<Vec<S
             let replicas = spec.replicas.unwrap_or(0);
             let ready = status.ready_replicas.unwrap_or(0);
 
+            let pod_labels = spec.template.metadata.and_then(|m| m.labels).unwrap_or_default();
+            let blocked_by = find_workload_blockers(&pdbs, &quotas, &pod_labels, ready, replicas, None);
+
             StatefulSetInfo {
                 name: meta.name.unwrap_or_default(),
                 namespace: meta.namespace.unwrap_or_default(),
                 ready: format!("{}/{}", ready, replicas),
                 age: format_age(meta.creation_timestamp.as_ref()),
+                blocked_by,
             }
         })
         .collect())
 }
 
+/// PodDisruptionBudgets and ResourceQuotas holding back one Deployment or StatefulSet,
+/// for detail views that do not have the listing at hand.
+pub async fn get_workload_blockers(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+) -> Result<Vec<String>, DomainError> {
+    let (pod_labels, ready, replicas, replica_failure) = match normalize_workload_kind(kind)? {
+        "Deployment" => {
+            let dep = Api::<Deployment>::namespaced(client.clone(), namespace).get(name).await?;
+            let spec = dep.spec.unwrap_or_default();
+            let status = dep.status.unwrap_or_default();
+            let replica_failure = status
+                .conditions
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .find(|c| c.type_ == "ReplicaFailure" && c.status == "True")
+                .and_then(|c| c.message.clone());
+            (spec.template.metadata.and_then(|m| m.labels), status.ready_replicas, spec.replicas, replica_failure)
+        }
+        "StatefulSet" => {
+            let ss = Api::<StatefulSet>::namespaced(client.clone(), namespace).get(name).await?;
+            let spec = ss.spec.unwrap_or_default();
+            let status = ss.status.unwrap_or_default();
+            (spec.template.metadata.and_then(|m| m.labels), status.ready_replicas, spec.replicas, None)
+        }
+        other => {
+            return Err(DomainError::Configuration(format!(
+                "Blockers are only reported for Deployments and StatefulSets, not {}",
+                other
+            )));
+        }
+    };
+
+    let (pdbs, quotas) = load_workload_policies(client, namespace).await;
+    Ok(find_workload_blockers(
+        &pdbs,
+        &quotas,
+        &pod_labels.unwrap_or_default(),
+        ready.unwrap_or(0),
+        replicas.unwrap_or(0),
+        replica_failure.as_deref(),
+    ))
+}
+
 pub async fn list_replicasets(client: &Client, namespace: &str) -> Result<Vec<ReplicaSetInfo>, DomainError> {
     let api: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
     let list = api.list(&ListParams::default()).await?;
@@ -202,3 +280,518 @@ pub async fn list_cronjobs(client: &Client, namespace: &str) -> Result<Vec<CronJ
         })
         .collect())
 }
+
+/// Canonical workload kind for user input such as `deploy`, `sts` or `statefulsets`.
+pub fn normalize_workload_kind(kind: &str) -> Result<&'static str, DomainError> {
+    match kind.to_lowercase().as_str() {
+        "deployment" | "deployments" | "deploy" => Ok("Deployment"),
+        "statefulset" | "statefulsets" | "sts" => Ok("StatefulSet"),
+        "daemonset" | "daemonsets" | "ds" => Ok("DaemonSet"),
+        "replicaset" | "replicasets" | "rs" => Ok("ReplicaSet"),
+        _ => Err(DomainError::Configuration(format!("Unsupported workload kind: {}", kind))),
+    }
+}
+
+async fn patch_typed<K>(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    patch: &Patch<Value>,
+    pp: &PatchParams,
+) -> Result<Value, DomainError>
+where
+    K: Resource<Scope = k8s_openapi::NamespaceResourceScope, DynamicType = ()>
+        + Clone
+        + DeserializeOwned
+        + Serialize
+        + std::fmt::Debug,
+{
+    let api: Api<K> = Api::namespaced(client.clone(), namespace);
+    let obj = api.patch(name, pp, patch).await?;
+    Ok(serde_json::to_value(obj)?)
+}
+
+async fn get_typed<K>(client: &Client, namespace: &str, name: &str) -> Result<Value, DomainError>
+where
+    K: Resource<Scope = k8s_openapi::NamespaceResourceScope, DynamicType = ()>
+        + Clone
+        + DeserializeOwned
+        + Serialize
+        + std::fmt::Debug,
+{
+    let api: Api<K> = Api::namespaced(client.clone(), namespace);
+    Ok(serde_json::to_value(api.get(name).await?)?)
+}
+
+async fn get_workload(client: &Client, namespace: &str, kind: &str, name: &str) -> Result<Value, DomainError> {
+    match kind {
+        "Deployment" => get_typed::<Deployment>(client, namespace, name).await,
+        "StatefulSet" => get_typed::<StatefulSet>(client, namespace, name).await,
+        "DaemonSet" => get_typed::<DaemonSet>(client, namespace, name).await,
+        "ReplicaSet" => get_typed::<ReplicaSet>(client, namespace, name).await,
+        _ => Err(DomainError::Configuration(format!("Unsupported workload kind: {}", kind))),
+    }
+}
+
+/// Patch a workload by kind, returning the resulting object as JSON.
+async fn patch_workload(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+    patch: &Patch<Value>,
+    pp: &PatchParams,
+) -> Result<Value, DomainError> {
+    match kind {
+        "Deployment" => patch_typed::<Deployment>(client, namespace, name, patch, pp).await,
+        "StatefulSet" => patch_typed::<StatefulSet>(client, namespace, name, patch, pp).await,
+        "DaemonSet" => patch_typed::<DaemonSet>(client, namespace, name, patch, pp).await,
+        "ReplicaSet" => patch_typed::<ReplicaSet>(client, namespace, name, patch, pp).await,
+        _ => Err(DomainError::Configuration(format!("Unsupported workload kind: {}", kind))),
+    }
+}
+
+async fn fetch_template_containers(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+) -> Result<Vec<Container>, DomainError> {
+    let pod_spec = match kind {
+        "Deployment" => {
+            let api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+            api.get(name).await?.spec.and_then(|s| s.template.spec)
+        }
+        "StatefulSet" => {
+            let api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+            api.get(name).await?.spec.and_then(|s| s.template.spec)
+        }
+        "DaemonSet" => {
+            let api: Api<DaemonSet> = Api::namespaced(client.clone(), namespace);
+            api.get(name).await?.spec.and_then(|s| s.template.spec)
+        }
+        "ReplicaSet" => {
+            let api: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+            api.get(name).await?.spec.and_then(|s| s.template).and_then(|t| t.spec)
+        }
+        _ => return Err(DomainError::Configuration(format!("Unsupported workload kind: {}", kind))),
+    };
+    pod_spec
+        .map(|ps| ps.containers)
+        .ok_or_else(|| DomainError::NotFound("No containers found".to_string()))
+}
+
+/// Set `spec.replicas` on a Deployment, StatefulSet or ReplicaSet.
+pub async fn scale_workload(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+    replicas: i32,
+) -> Result<(), DomainError> {
+    let kind = normalize_workload_kind(kind)?;
+    if kind == "DaemonSet" {
+        return Err(DomainError::Configuration(
+            "DaemonSets run one pod per eligible node and cannot be scaled".to_string(),
+        ));
+    }
+    let patch = serde_json::json!({
+        "spec": { "replicas": replicas }
+    });
+    patch_workload(client, namespace, kind, name, &Patch::Merge(patch), &PatchParams::apply("k0")).await?;
+    Ok(())
+}
+
+/// Trigger a rolling restart the way `kubectl rollout restart` does, by stamping the pod template.
+pub async fn restart_workload(client: &Client, namespace: &str, kind: &str, name: &str) -> Result<(), DomainError> {
+    let kind = normalize_workload_kind(kind)?;
+    if kind == "ReplicaSet" {
+        // A ReplicaSet never replaces existing pods when its template changes
+        return Err(DomainError::Configuration(
+            "ReplicaSets cannot be restarted; restart the owning Deployment instead".to_string(),
+        ));
+    }
+    let now = chrono::Utc::now().to_rfc3339();
+    let patch = serde_json::json!({
+        "spec": {
+            "template": {
+                "metadata": {
+                    "annotations": {
+                        "kubectl.kubernetes.io/restartedAt": now
+                    }
+                }
+            }
+        }
+    });
+    patch_workload(client, namespace, kind, name, &Patch::Merge(patch), &PatchParams::apply("k0")).await?;
+    Ok(())
+}
+
+pub async fn update_workload_resources(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+    container_name: &str,
+    requests_cpu: &str,
+    requests_memory: &str,
+    limits_cpu: &str,
+    limits_memory: &str,
+) -> Result<(), DomainError> {
+    let kind = normalize_workload_kind(kind)?;
+    let containers = fetch_template_containers(client, namespace, kind, name).await?;
+    if !containers.iter().any(|c| c.name == container_name) {
+        return Err(DomainError::NotFound(format!("Container {} not found", container_name)));
+    }
+
+    let mut requests = serde_json::Map::new();
+    if !requests_cpu.is_empty() { requests.insert("cpu".into(), serde_json::json!(requests_cpu)); }
+    if !requests_memory.is_empty() { requests.insert("memory".into(), serde_json::json!(requests_memory)); }
+
+    let mut limits = serde_json::Map::new();
+    if !limits_cpu.is_empty() { limits.insert("cpu".into(), serde_json::json!(limits_cpu)); }
+    if !limits_memory.is_empty() { limits.insert("memory".into(), serde_json::json!(limits_memory)); }
+
+    let patch = serde_json::json!({
+        "spec": {
+            "template": {
+                "spec": {
+                    "containers": [{
+                        "name": container_name,
+                        "resources": {
+                            "requests": requests,
+                            "limits": limits,
+                        }
+                    }]
+                }
+            }
+        }
+    });
+    patch_workload(client, namespace, kind, name, &Patch::Strategic(patch), &PatchParams::apply("k0")).await?;
+    Ok(())
+}
+
+/// Set the RollingUpdate partition of a StatefulSet: only ordinals >= `partition` get the new revision.
+pub async fn set_statefulset_partition(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    partition: i32,
+) -> Result<(), DomainError> {
+    let api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+    let sts = api.get(name).await?;
+    let strategy = sts
+        .spec
+        .as_ref()
+        .and_then(|s| s.update_strategy.as_ref())
+        .and_then(|u| u.type_.clone())
+        .unwrap_or_else(|| "RollingUpdate".to_string());
+    if strategy != "RollingUpdate" {
+        return Err(DomainError::Configuration(format!(
+            "StatefulSet {} uses the {} update strategy; partitions only apply to RollingUpdate",
+            name, strategy
+        )));
+    }
+    if partition < 0 {
+        return Err(DomainError::Configuration("Partition must not be negative".to_string()));
+    }
+    let patch = serde_json::json!({
+        "spec": {
+            "updateStrategy": {
+                "type": "RollingUpdate",
+                "rollingUpdate": { "partition": partition }
+            }
+        }
+    });
+    api.patch(name, &PatchParams::apply("k0"), &Patch::Merge(&patch)).await?;
+    Ok(())
+}
+
+fn parse_probe_port(port: Option<&str>, probe_type: &str) -> Result<Value, DomainError> {
+    let port = port
+        .filter(|p| !p.is_empty())
+        .ok_or_else(|| DomainError::Configuration(format!("{} probe requires a port", probe_type)))?;
+    Ok(match port.parse::<i32>() {
+        Ok(n) => serde_json::json!(n),
+        Err(_) => serde_json::json!(port),
+    })
+}
+
+/// Build a probe that replaces the existing one wholesale, so switching e.g. from
+/// httpGet to tcpSocket doesn't leave both handlers behind.
+fn build_probe_patch(probe: &ProbeEdit) -> Result<Value, DomainError> {
+    let mut obj = serde_json::Map::new();
+    obj.insert("$patch".into(), serde_json::json!("replace"));
+
+    match probe.probe_type.to_lowercase().as_str() {
+        "http" | "httpget" => {
+            let mut http = serde_json::Map::new();
+            http.insert("path".into(), serde_json::json!(probe.path.clone().unwrap_or_else(|| "/".to_string())));
+            http.insert("port".into(), parse_probe_port(probe.port.as_deref(), "HTTP")?);
+            if let Some(scheme) = &probe.scheme {
+                http.insert("scheme".into(), serde_json::json!(scheme.to_uppercase()));
+            }
+            obj.insert("httpGet".into(), Value::Object(http));
+        }
+        "tcp" | "tcpsocket" => {
+            obj.insert(
+                "tcpSocket".into(),
+                serde_json::json!({ "port": parse_probe_port(probe.port.as_deref(), "TCP")? }),
+            );
+        }
+        "grpc" => {
+            let port = probe
+                .port
+                .as_deref()
+                .and_then(|p| p.parse::<i32>().ok())
+                .ok_or_else(|| DomainError::Configuration("gRPC probe requires a numeric port".to_string()))?;
+            obj.insert("grpc".into(), serde_json::json!({ "port": port }));
+        }
+        "exec" => {
+            if probe.command.is_empty() {
+                return Err(DomainError::Configuration("Exec probe requires a command".to_string()));
+            }
+            obj.insert("exec".into(), serde_json::json!({ "command": probe.command }));
+        }
+        other => {
+            return Err(DomainError::Configuration(format!("Unsupported probe type: {}", other)));
+        }
+    }
+
+    let timings = [
+        ("initialDelaySeconds", probe.initial_delay_seconds),
+        ("timeoutSeconds", probe.timeout_seconds),
+        ("periodSeconds", probe.period_seconds),
+        ("successThreshold", probe.success_threshold),
+        ("failureThreshold", probe.failure_threshold),
+    ];
+    for (key, value) in timings {
+        if let Some(v) = value {
+            obj.insert(key.into(), serde_json::json!(v));
+        }
+    }
+    Ok(Value::Object(obj))
+}
+
+fn build_env_patch(env: &EnvVarEdit) -> Result<Value, DomainError> {
+    if env.name.trim().is_empty() {
+        return Err(DomainError::Configuration("Environment variable name must not be empty".to_string()));
+    }
+    let sources = [env.value.is_some(), env.secret_ref.is_some(), env.config_map_ref.is_some()];
+    if sources.iter().filter(|s| **s).count() != 1 {
+        return Err(DomainError::Configuration(format!(
+            "Environment variable {} must have exactly one of value, secret or configMap",
+            env.name
+        )));
+    }
+
+    let key_ref = |r: &KeyRefEdit| {
+        let mut v = serde_json::json!({ "name": r.name, "key": r.key });
+        if let Some(optional) = r.optional {
+            v["optional"] = serde_json::json!(optional);
+        }
+        v
+    };
+    // Null out the other form so switching between a literal and a reference doesn't leave both
+    Ok(if let Some(r) = &env.secret_ref {
+        serde_json::json!({ "name": env.name, "value": null, "valueFrom": { "secretKeyRef": key_ref(r) } })
+    } else if let Some(r) = &env.config_map_ref {
+        serde_json::json!({ "name": env.name, "value": null, "valueFrom": { "configMapKeyRef": key_ref(r) } })
+    } else {
+        serde_json::json!({ "name": env.name, "value": env.value.clone().unwrap_or_default(), "valueFrom": null })
+    })
+}
+
+/// Translate a structured edit into a strategic merge patch. Containers and env vars merge
+/// by name, so only the touched entries need to be listed.
+pub fn build_workload_patch(kind: &str, edit: &WorkloadEdit, existing: &[Container]) -> Result<Value, DomainError> {
+    let mut containers = Vec::new();
+    for c in &edit.containers {
+        if !existing.iter().any(|e| e.name == c.name) {
+            return Err(DomainError::NotFound(format!("Container {} not found", c.name)));
+        }
+        let mut patch = serde_json::Map::new();
+        patch.insert("name".into(), serde_json::json!(c.name));
+        if let Some(image) = c.image.as_deref().filter(|i| !i.trim().is_empty()) {
+            patch.insert("image".into(), serde_json::json!(image.trim()));
+        }
+
+        let mut env: Vec<Value> = c.set_env.iter().map(build_env_patch).collect::<Result<_, _>>()?;
+        env.extend(c.remove_env.iter().map(|n| serde_json::json!({ "name": n, "$patch": "delete" })));
+        if !env.is_empty() {
+            patch.insert("env".into(), Value::Array(env));
+        }
+
+        if c.remove_liveness {
+            patch.insert("livenessProbe".into(), Value::Null);
+        } else if let Some(p) = &c.liveness {
+            patch.insert("livenessProbe".into(), build_probe_patch(p)?);
+        }
+        if c.remove_readiness {
+            patch.insert("readinessProbe".into(), Value::Null);
+        } else if let Some(p) = &c.readiness {
+            patch.insert("readinessProbe".into(), build_probe_patch(p)?);
+        }
+        containers.push(Value::Object(patch));
+    }
+
+    let mut spec = serde_json::Map::new();
+    if !containers.is_empty() {
+        spec.insert("template".into(), serde_json::json!({ "spec": { "containers": containers } }));
+    }
+    if let Some(v) = edit.min_ready_seconds {
+        spec.insert("minReadySeconds".into(), serde_json::json!(v));
+    }
+    if let Some(v) = edit.revision_history_limit {
+        spec.insert("revisionHistoryLimit".into(), serde_json::json!(v));
+    }
+    if let Some(v) = edit.progress_deadline_seconds {
+        if kind != "Deployment" {
+            return Err(DomainError::Configuration(
+                "progressDeadlineSeconds only applies to Deployments".to_string(),
+            ));
+        }
+        spec.insert("progressDeadlineSeconds".into(), serde_json::json!(v));
+    }
+    if spec.is_empty() {
+        return Err(DomainError::Configuration("Nothing to change".to_string()));
+    }
+    Ok(serde_json::json!({ "spec": spec }))
+}
+
+async fn run_workload_edit(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+    edit: &WorkloadEdit,
+    dry_run: bool,
+) -> Result<WorkloadEditPreview, DomainError> {
+    let kind = normalize_workload_kind(kind)?;
+    if kind == "ReplicaSet" {
+        return Err(DomainError::Configuration(
+            "Edit the owning Deployment instead; ReplicaSets don't roll out template changes".to_string(),
+        ));
+    }
+    let containers = fetch_template_containers(client, namespace, kind, name).await?;
+    let patch = build_workload_patch(kind, edit, &containers)?;
+
+    let current = get_workload(client, namespace, kind, name).await?;
+    let mut pp = PatchParams::apply("k0");
+    pp.dry_run = dry_run;
+    let updated = patch_workload(client, namespace, kind, name, &Patch::Strategic(patch.clone()), &pp).await?;
+
+    let diff = diff_lines(
+        &serde_yaml::to_string(&current["spec"])?,
+        &serde_yaml::to_string(&updated["spec"])?,
+    );
+    Ok(WorkloadEditPreview {
+        kind: kind.to_string(),
+        name: name.to_string(),
+        patch,
+        changed: has_changes(&diff),
+        diff,
+    })
+}
+
+/// Server-side dry run of a structured edit, returning the patch and the resulting spec diff.
+pub async fn preview_workload_edit(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+    edit: &WorkloadEdit,
+) -> Result<WorkloadEditPreview, DomainError> {
+    run_workload_edit(client, namespace, kind, name, edit, true).await
+}
+
+pub async fn apply_workload_edit(
+    client: &Client,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+    edit: &WorkloadEdit,
+) -> Result<WorkloadEditPreview, DomainError> {
+    run_workload_edit(client, namespace, kind, name, edit, false).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn container(name: &str) -> Container {
+        Container { name: name.to_string(), ..Default::default() }
+    }
+
+    fn env(name: &str, value: Option<&str>, secret: Option<(&str, &str)>) -> EnvVarEdit {
+        EnvVarEdit {
+            name: name.to_string(),
+            value: value.map(String::from),
+            secret_ref: secret.map(|(name, key)| KeyRefEdit { name: name.to_string(), key: key.to_string(), optional: None }),
+            config_map_ref: None,
+        }
+    }
+
+    #[test]
+    fn test_build_env_patch() {
+        assert_eq!(
+            build_env_patch(&env("MODE", Some("prod"), None)).unwrap(),
+            json!({ "name": "MODE", "value": "prod", "valueFrom": null })
+        );
+        assert_eq!(
+            build_env_patch(&env("PASSWORD", None, Some(("db", "password")))).unwrap(),
+            json!({ "name": "PASSWORD", "value": null, "valueFrom": { "secretKeyRef": { "name": "db", "key": "password" } } })
+        );
+
+        let err = |e: EnvVarEdit| build_env_patch(&e).unwrap_err().to_string();
+        assert!(err(env("BOTH", Some("x"), Some(("db", "password")))).contains("exactly one"));
+        assert!(err(env("NONE", None, None)).contains("exactly one"));
+        assert!(err(env(" ", Some("x"), None)).contains("must not be empty"));
+    }
+
+    #[test]
+    fn test_build_workload_patch() {
+        let existing = [container("web"), container("sidecar")];
+        let edit = WorkloadEdit {
+            containers: vec![ContainerEdit {
+                name: "web".to_string(),
+                image: Some(" nginx:1.27 ".to_string()),
+                set_env: vec![env("MODE", Some("prod"), None)],
+                remove_env: vec!["DEBUG".to_string()],
+                remove_liveness: true,
+                ..Default::default()
+            }],
+            min_ready_seconds: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(
+            build_workload_patch("Deployment", &edit, &existing).unwrap(),
+            json!({
+                "spec": {
+                    "template": { "spec": { "containers": [{
+                        "name": "web",
+                        "image": "nginx:1.27",
+                        "env": [
+                            { "name": "MODE", "value": "prod", "valueFrom": null },
+                            { "name": "DEBUG", "$patch": "delete" }
+                        ],
+                        "livenessProbe": null
+                    }] } },
+                    "minReadySeconds": 10
+                }
+            })
+        );
+
+        let missing = WorkloadEdit {
+            containers: vec![ContainerEdit { name: "db".to_string(), ..Default::default() }],
+            ..Default::default()
+        };
+        assert!(matches!(build_workload_patch("Deployment", &missing, &existing), Err(DomainError::NotFound(_))));
+
+        let deadline = WorkloadEdit { progress_deadline_seconds: Some(300), ..Default::default() };
+        assert!(build_workload_patch("StatefulSet", &deadline, &existing).is_err());
+        assert!(build_workload_patch("Deployment", &deadline, &existing).is_ok());
+        assert!(build_workload_patch("Deployment", &WorkloadEdit::default(), &existing).is_err());
+    }
+}
@@ -1 +1,2 @@
 pub mod pod_watcher;
+pub mod rollout_watcher;
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use futures::StreamExt;
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
+use kube::runtime::watcher::{self, Event as WatcherEvent};
+use kube::runtime::WatchStreamExt;
+use kube::{Api, Client};
+use tauri::Emitter;
+use tokio::time::Duration;
+
+use crate::infrastructure::kubernetes::deployment_repository::{revision_of, rollout_progress, REVISION_ANNOTATION};
+use crate::infrastructure::kubernetes::helpers::format_label_selector;
+
+/// Watch errors in a row (each retried with backoff) before the session reports failure.
+const MAX_CONSECUTIVE_ERRORS: u32 = 5;
+
+/// Watch a deployment and its ReplicaSets, emitting `rollout-status-{session_id}` events
+/// until the rollout completes or exceeds its progress deadline (like `kubectl rollout status`).
+pub async fn run_rollout_watcher(
+    client: Client,
+    namespace: String,
+    name: String,
+    session_id: String,
+    app_handle: tauri::AppHandle,
+) {
+    let event_name = format!("rollout-status-{}", session_id);
+    let error_event = format!("rollout-status-error-{}", session_id);
+    let dep_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+    let rs_api: Api<ReplicaSet> = Api::namespaced(client.clone(), &namespace);
+
+    let mut deployment = match dep_api.get_opt(&name).await {
+        Ok(Some(dep)) => dep,
+        Ok(None) => {
+            let _ = app_handle.emit(&error_event, format!("Deployment {} not found", name));
+            return;
+        }
+        Err(e) => {
+            let _ = app_handle.emit(&error_event, e.to_string());
+            return;
+        }
+    };
+    let uid = deployment.metadata.uid.clone().unwrap_or_default();
+
+    // Only this deployment's ReplicaSets; the owner check below drops any other
+    // deployment's sets that happen to match the same selector
+    let mut rs_config = watcher::Config::default();
+    if let Some(selector) = deployment.spec.as_ref().map(|s| format_label_selector(&s.selector)) {
+        if selector != "<all>" {
+            rs_config = rs_config.labels(&selector);
+        }
+    }
+    let dep_config = watcher::Config::default().fields(&format!("metadata.name={}", name));
+    let mut dep_stream = watcher::watcher(dep_api, dep_config).default_backoff().boxed();
+    let mut rs_stream = watcher::watcher(rs_api, rs_config).default_backoff().boxed();
+
+    let mut replica_sets: HashMap<String, ReplicaSet> = HashMap::new();
+    let mut consecutive_errors = 0;
+    // Re-evaluate periodically so a rollout that stops progressing hits its deadline
+    let mut tick = tokio::time::interval(Duration::from_secs(5));
+
+    loop {
+        let result = tokio::select! {
+            item = dep_stream.next() => match item {
+                Some(Ok(WatcherEvent::Apply(dep))) | Some(Ok(WatcherEvent::InitApply(dep))) => {
+                    deployment = dep;
+                    Ok(())
+                }
+                Some(Ok(WatcherEvent::Delete(_))) => {
+                    let _ = app_handle.emit(&error_event, "Deployment was deleted");
+                    break;
+                }
+                Some(Ok(_)) => Ok(()),
+                Some(Err(e)) => Err(e),
+                None => break,
+            },
+            item = rs_stream.next() => match item {
+                Some(Ok(WatcherEvent::Apply(rs))) | Some(Ok(WatcherEvent::InitApply(rs))) => {
+                    if owned_by(&rs, &uid) {
+                        replica_sets.insert(rs.metadata.name.clone().unwrap_or_default(), rs);
+                    }
+                    Ok(())
+                }
+                Some(Ok(WatcherEvent::Delete(rs))) => {
+                    replica_sets.remove(&rs.metadata.name.clone().unwrap_or_default());
+                    Ok(())
+                }
+                Some(Ok(WatcherEvent::Init)) => {
+                    replica_sets.clear();
+                    Ok(())
+                }
+                Some(Ok(WatcherEvent::InitDone)) => Ok(()),
+                Some(Err(e)) => Err(e),
+                None => break,
+            },
+            _ = tick.tick() => Ok(()),
+        };
+
+        // The streams back off and retry on their own; give up once errors persist
+        match result {
+            Ok(()) => consecutive_errors = 0,
+            Err(e) => {
+                consecutive_errors += 1;
+                if consecutive_errors >= MAX_CONSECUTIVE_ERRORS {
+                    let _ = app_handle.emit(&error_event, e.to_string());
+                    break;
+                }
+                continue;
+            }
+        }
+
+        let progress = rollout_progress(&deployment, &current_replica_set(&deployment, &replica_sets));
+        let done = progress.done;
+        let _ = app_handle.emit(&event_name, &progress);
+        if done {
+            break;
+        }
+    }
+}
+
+fn owned_by(rs: &ReplicaSet, uid: &str) -> bool {
+    rs.metadata
+        .owner_references
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .any(|r| r.kind == "Deployment" && r.uid == uid)
+}
+
+/// Name of the ReplicaSet carrying the deployment's current revision, if it exists yet.
+fn current_replica_set(dep: &Deployment, replica_sets: &HashMap<String, ReplicaSet>) -> String {
+    let revision = dep.metadata.annotations.as_ref().and_then(|a| a.get(REVISION_ANNOTATION));
+    replica_sets
+        .values()
+        .find(|rs| {
+            owned_by(rs, dep.metadata.uid.as_deref().unwrap_or_default()) && revision == Some(&revision_of(rs))
+        })
+        .and_then(|rs| rs.metadata.name.clone())
+        .unwrap_or_default()
+}
@@ -18,6 +18,7 @@ pub struct AppState {
     pub chat_sessions: Arc<Mutex<HashMap<String, ChatSession>>>,
     pub port_forwards: Arc<Mutex<HashMap<String, (PortForwardEntry, std::process::Child)>>>,
     pub minikube_sessions: Arc<Mutex<HashMap<String, tokio::task::JoinHandle<()>>>>,
+    pub rollout_sessions: Arc<Mutex<HashMap<String, tokio::task::JoinHandle<()>>>>,
     pub config_db: Arc<ConfigDB>,
 }
 
@@ -31,6 +32,7 @@ impl AppState {
             chat_sessions: Arc::new(Mutex::new(HashMap::new())),
             port_forwards: Arc::new(Mutex::new(HashMap::new())),
             minikube_sessions: Arc::new(Mutex::new(HashMap::new())),
+            rollout_sessions: Arc::new(Mutex::new(HashMap::new())),
             config_db,
         }
     }
@@ -0,0 +1,124 @@
+use serde_json::Value;
+use tauri::State;
+
+use crate::application::handlers::bulk_handler::BulkHandler;
+use crate::domain::entities::{BulkItemResult, MetadataEdit, ResourceRef};
+use crate::interfaces::state::AppState;
+use crate::interfaces::tauri_commands::sanitize_error_msg;
+
+#[tauri::command]
+pub async fn delete_resources(
+    resources: Vec<ResourceRef>,
+    state: State<'_, AppState>,
+) -> Result<Vec<BulkItemResult>, String> {
+    BulkHandler::delete_resources(&state.client_manager, resources)
+        .await
+        .map_err(|e| sanitize_error_msg(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn patch_resources(
+    resources: Vec<ResourceRef>,
+    patch_json: Value,
+    state: State<'_, AppState>,
+) -> Result<Vec<BulkItemResult>, String> {
+    BulkHandler::patch_resources(&state.client_manager, resources, &patch_json)
+        .await
+        .map_err(|e| sanitize_error_msg(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn restart_workloads(
+    resources: Vec<ResourceRef>,
+    state: State<'_, AppState>,
+) -> Result<Vec<BulkItemResult>, String> {
+    BulkHandler::restart_workloads(&state.client_manager, resources)
+        .await
+        .map_err(|e| sanitize_error_msg(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn delete_pods(
+    names: Vec<String>,
+    state: State<'_, AppState>,
+) -> Result<Vec<BulkItemResult>, String> {
+    BulkHandler::delete_pods(&state.client_manager, names)
+        .await
+        .map_err(|e| sanitize_error_msg(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn delete_resources_by_selector(
+    kind: String,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    all_namespaces: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<Vec<BulkItemResult>, String> {
+    BulkHandler::delete_resources_by_selector(
+        &state.client_manager,
+        &kind,
+        label_selector.as_deref(),
+        field_selector.as_deref(),
+        all_namespaces.unwrap_or(false),
+    )
+    .await
+    .map_err(|e| sanitize_error_msg(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn delete_pods_by_selector(
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    reason: Option<String>,
+    all_namespaces: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<Vec<BulkItemResult>, String> {
+    BulkHandler::delete_pods_by_selector(
+        &state.client_manager,
+        label_selector.as_deref(),
+        field_selector.as_deref(),
+        reason.as_deref(),
+        all_namespaces.unwrap_or(false),
+    )
+    .await
+    .map_err(|e| sanitize_error_msg(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn update_resource_metadata(
+    resources: Vec<ResourceRef>,
+    labels: Option<MetadataEdit>,
+    annotations: Option<MetadataEdit>,
+    state: State<'_, AppState>,
+) -> Result<Vec<BulkItemResult>, String> {
+    BulkHandler::update_metadata(
+        &state.client_manager,
+        resources,
+        &labels.unwrap_or_default(),
+        &annotations.unwrap_or_default(),
+    )
+    .await
+    .map_err(|e| sanitize_error_msg(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn update_resource_metadata_by_selector(
+    kind: String,
+    label_selector: String,
+    all_namespaces: Option<bool>,
+    labels: Option<MetadataEdit>,
+    annotations: Option<MetadataEdit>,
+    state: State<'_, AppState>,
+) -> Result<Vec<BulkItemResult>, String> {
+    BulkHandler::update_metadata_by_selector(
+        &state.client_manager,
+        &kind,
+        &label_selector,
+        all_namespaces.unwrap_or(false),
+        &labels.unwrap_or_default(),
+        &annotations.unwrap_or_default(),
+    )
+    .await
+    .map_err(|e| sanitize_error_msg(e.to_string()))
+}
@@ -112,52 +112,31 @@ pub async fn execute_chat_action(
             let replicas = params["replicas"]
                 .as_i64()
                 .ok_or("Missing 'replicas' param for scale")? as i32;
-
-            use k8s_openapi::api::apps::v1::Deployment;
-            use kube::{api::PatchParams, Api};
-            let api: Api<Deployment> = Api::namespaced(client, &ns);
-            let patch = serde_json::json!({
-                "spec": { "replicas": replicas }
-            });
-            api.patch(
-                name,
-                &PatchParams::apply("k0"),
-                &kube::api::Patch::Merge(&patch),
+            let kind = crate::infrastructure::kubernetes::workload_repository::normalize_workload_kind(
+                params["kind"].as_str().unwrap_or("Deployment"),
             )
-            .await
             .map_err(|e| e.to_string())?;
 
-            Ok(format!("Scaled deployment/{} to {} replicas", name, replicas))
+            crate::application::handlers::workload_handler::WorkloadHandler::scale(&client, &ns, kind, name, replicas)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            Ok(format!("Scaled {}/{} to {} replicas", kind.to_lowercase(), name, replicas))
         }
         "restart" => {
             let name = params["name"]
                 .as_str()
                 .ok_or("Missing 'name' param for restart")?;
-
-            use k8s_openapi::api::apps::v1::Deployment;
-            use kube::{api::PatchParams, Api};
-            let api: Api<Deployment> = Api::namespaced(client, &ns);
-            let now = chrono::Utc::now().to_rfc3339();
-            let patch = serde_json::json!({
-                "spec": {
-                    "template": {
-                        "metadata": {
-                            "annotations": {
-                                "kubectl.kubernetes.io/restartedAt": now
-                            }
-                        }
-                    }
-                }
-            });
-            api.patch(
-                name,
-                &PatchParams::apply("k0"),
-                &kube::api::Patch::Merge(&patch),
+            let kind = crate::infrastructure::kubernetes::workload_repository::normalize_workload_kind(
+                params["kind"].as_str().unwrap_or("Deployment"),
             )
-            .await
             .map_err(|e| e.to_string())?;
 
-            Ok(format!("Restarted deployment/{}", name))
+            crate::application::handlers::workload_handler::WorkloadHandler::restart(&client, &ns, kind, name)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            Ok(format!("Restarted {}/{}", kind.to_lowercase(), name))
         }
         "delete_pod" => {
             let name = params["name"]
@@ -175,62 +154,30 @@ pub async fn execute_chat_action(
                 .as_str()
                 .ok_or("Missing 'yaml_content' param for apply_yaml")?;
 
-            // Parse the YAML to extract resource info
-            let doc: Value =
-                serde_yaml::from_str(yaml_content).map_err(|e| format!("Invalid YAML: {}", e))?;
-            let kind = doc["kind"]
-                .as_str()
-                .ok_or("YAML missing 'kind'")?
-                .to_lowercase();
-            let api_version = doc["apiVersion"]
-                .as_str()
-                .ok_or("YAML missing 'apiVersion'")?;
-            let name = doc["metadata"]["name"]
-                .as_str()
-                .ok_or("YAML missing 'metadata.name'")?;
-
-            // Parse group/version from apiVersion
-            let (group, version) = if api_version.contains('/') {
-                let parts: Vec<&str> = api_version.splitn(2, '/').collect();
-                (parts[0].to_string(), parts[1].to_string())
-            } else {
-                (String::new(), api_version.to_string())
-            };
-
-            // Build plural (simple heuristic)
-            let plural = if kind.ends_with('s') {
-                format!("{}es", kind)
-            } else {
-                format!("{}s", kind)
-            };
-
-            let api_resource = kube::api::ApiResource {
-                group: group.clone(),
-                version: version.clone(),
-                api_version: api_version.to_string(),
-                kind: kind.clone(),
-                plural: plural.clone(),
-            };
-
-            let api: Api<kube::api::DynamicObject> =
-                Api::namespaced_with(client, &ns, &api_resource);
-
-            let data: kube::api::DynamicObject =
-                serde_json::from_value(
-                    serde_json::to_value(&doc)
-                        .map_err(|e| format!("Failed to serialize YAML: {}", e))?,
-                )
-                .map_err(|e| format!("Failed to parse as dynamic object: {}", e))?;
-
-            let _: kube::api::DynamicObject = api.patch(
-                name,
-                &kube::api::PatchParams::apply("k0"),
-                &kube::api::Patch::Apply(&data),
+            let results = crate::application::handlers::editing_handler::EditingHandler::apply_manifests(
+                &state.client_manager,
+                yaml_content,
+                false,
             )
             .await
-            .map_err(|e: kube::Error| e.to_string())?;
+            .map_err(|e| e.to_string())?;
 
-            Ok(format!("Applied {}/{}", kind, name))
+            let applied: Vec<String> = results
+                .iter()
+                .filter(|r| r.success)
+                .map(|r| format!("{}/{}", r.kind.to_lowercase(), r.name))
+                .collect();
+            let failed: Vec<String> = results
+                .iter()
+                .filter(|r| !r.success)
+                .map(|r| format!("{}/{}: {}", r.kind.to_lowercase(), r.name, r.error))
+                .collect();
+
+            if failed.is_empty() {
+                Ok(format!("Applied {}", applied.join(", ")))
+            } else {
+                Err(format!("Failed to apply {}", failed.join("; ")))
+            }
         }
         "patch_resource" => {
             let group = params["group"].as_str().unwrap_or("");
@@ -1,6 +1,7 @@
+use std::collections::BTreeMap;
 use tauri::State;
 
-use crate::domain::entities::{ContextInfo, NamespaceInfo};
+use crate::domain::entities::{ApiResourceInfo, ContextInfo, NamespaceDetailInfo, NamespaceInfo};
 use crate::interfaces::state::AppState;
 
 #[tauri::command]
@@ -30,6 +31,29 @@ pub async fn set_active_context(
     .map_err(Into::into)
 }
 
+#[tauri::command]
+pub async fn get_api_resources(
+    refresh: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<Vec<ApiResourceInfo>, String> {
+    crate::application::handlers::cluster_handler::ClusterHandler::list_api_resources(
+        &state.client_manager,
+        refresh.unwrap_or(false),
+    )
+    .await
+    .map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn resolve_api_resource(
+    kind: String,
+    state: State<'_, AppState>,
+) -> Result<ApiResourceInfo, String> {
+    crate::application::handlers::cluster_handler::ClusterHandler::resolve_api_resource(&state.client_manager, &kind)
+        .await
+        .map_err(Into::into)
+}
+
 #[tauri::command]
 pub async fn get_namespaces(state: State<'_, AppState>) -> Result<Vec<NamespaceInfo>, String> {
     let client = state.client_manager.get_client_for_context().await.map_err(String::from)?;
@@ -38,6 +62,39 @@ pub async fn get_namespaces(state: State<'_, AppState>) -> Result<Vec<NamespaceI
         .map_err(Into::into)
 }
 
+#[tauri::command]
+pub async fn create_namespace(
+    name: String,
+    labels: Option<BTreeMap<String, String>>,
+    state: State<'_, AppState>,
+) -> Result<NamespaceInfo, String> {
+    let client = state.client_manager.get_client_for_context().await.map_err(String::from)?;
+    crate::application::handlers::cluster_handler::ClusterHandler::create_namespace(&client, &name, &labels.unwrap_or_default())
+        .await
+        .map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn delete_namespace(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let client = state.client_manager.get_client_for_context().await.map_err(String::from)?;
+    crate::application::handlers::cluster_handler::ClusterHandler::delete_namespace(&client, &name)
+        .await
+        .map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_namespace_detail(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<NamespaceDetailInfo, String> {
+    crate::application::handlers::cluster_handler::ClusterHandler::get_namespace_detail(&state.client_manager, &name)
+        .await
+        .map_err(Into::into)
+}
+
 #[tauri::command]
 pub async fn set_active_namespace(
     namespace: String,
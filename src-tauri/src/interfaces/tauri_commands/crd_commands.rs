@@ -17,7 +17,7 @@ pub async fn get_crds(state: State<'_, AppState>) -> Result<Vec<CRDInfo>, String
 #[tauri::command]
 pub async fn get_crd_instances(
     group: String,
-    version: String,
+    version: Option<String>,
     plural: String,
     scope: String,
     state: State<'_, AppState>,
@@ -27,7 +27,24 @@ pub async fn get_crd_instances(
         .get_active_client()
         .await
         .map_err(String::from)?;
-    CRDHandler::list_crd_instances(&client, &ns, &group, &version, &plural, &scope)
+    CRDHandler::list_crd_instances(&client, &ns, &group, version.as_deref(), &plural, &scope)
+        .await
+        .map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_crd_schema(
+    group: String,
+    plural: String,
+    version: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    let client = state
+        .client_manager
+        .get_client_for_context()
+        .await
+        .map_err(String::from)?;
+    CRDHandler::get_crd_schema(&client, &group, &plural, version.as_deref())
         .await
         .map_err(Into::into)
 }
@@ -1,6 +1,10 @@
 use tauri::State;
 
-use crate::application::handlers::{gateway_handler::GatewayHandler, pod_handler::PodHandler};
+use crate::application::handlers::{
+    autoscaling_handler::AutoscalingHandler, gateway_handler::GatewayHandler,
+    networkpolicy_handler::NetworkPolicyHandler, pod_handler::PodHandler, policy_handler::PolicyHandler,
+    workload_handler::WorkloadHandler,
+};
 use crate::domain::entities::*;
 use crate::interfaces::state::AppState;
 
@@ -13,6 +17,33 @@ pub async fn get_pod_detail(
     PodHandler::get_detail(&client, &ns, &name).await.map_err(Into::into)
 }
 
+#[tauri::command]
+pub async fn get_deployment_detail(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<DeploymentDetailInfo, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::get_deployment_detail(&client, &ns, &name).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_job_detail(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<JobDetailInfo, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::get_job_detail(&client, &ns, &name).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_service_detail(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<ServiceDetailInfo, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    GatewayHandler::get_service_detail(&client, &ns, &name).await.map_err(Into::into)
+}
+
 #[tauri::command]
 pub async fn get_ingress_detail(
     name: String,
@@ -48,3 +79,84 @@ pub async fn get_grpcroute_detail(
     let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
     GatewayHandler::get_grpcroute_detail(&client, &ns, &name).await.map_err(Into::into)
 }
+
+#[tauri::command]
+pub async fn get_hpa_detail(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<HpaDetailInfo, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    AutoscalingHandler::get_hpa_detail(&client, &ns, &name).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_pdb_detail(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<PdbDetailInfo, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    PolicyHandler::get_pdb_detail(&client, &ns, &name).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_resource_quota_detail(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<ResourceQuotaDetailInfo, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    PolicyHandler::get_resource_quota_detail(&client, &ns, &name).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_limit_range_detail(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<LimitRangeDetailInfo, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    PolicyHandler::get_limit_range_detail(&client, &ns, &name).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_workload_blockers(
+    kind: String,
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    PolicyHandler::get_workload_blockers(&client, &ns, &kind, &name).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_network_policy_detail(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<NetworkPolicyDetailInfo, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    NetworkPolicyHandler::get_network_policy_detail(&client, &ns, &name).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn analyze_network_reachability(
+    source_namespace: Option<String>,
+    source_pod: String,
+    dest_namespace: Option<String>,
+    dest_pod: String,
+    port: i32,
+    protocol: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<ReachabilityResult, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    let source_ns = source_namespace.unwrap_or_else(|| ns.clone());
+    let dest_ns = dest_namespace.unwrap_or(ns);
+    NetworkPolicyHandler::analyze_reachability(
+        &client,
+        &source_ns,
+        &source_pod,
+        &dest_ns,
+        &dest_pod,
+        port,
+        protocol.as_deref().unwrap_or("TCP"),
+    )
+    .await
+    .map_err(Into::into)
+}
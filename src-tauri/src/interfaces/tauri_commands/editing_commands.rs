@@ -1,89 +1,233 @@
+use kube::{discovery::ApiResource, Client};
 use serde_json::Value;
 use tauri::State;
 
 use crate::application::handlers::editing_handler::EditingHandler;
-use crate::domain::entities::GenericResourceDetailInfo;
+use crate::domain::entities::{
+    ApplyResult, DryRunResult, FieldOwnershipInfo, GenericResourceDetailInfo, ManifestApplyResult,
+    SchemaValidationResult,
+};
+use crate::infrastructure::kubernetes::helpers::build_api_resource;
 use crate::interfaces::state::AppState;
 use crate::interfaces::tauri_commands::sanitize_error_msg;
 
+/// Resolve the resource type a command targets. Callers that already know the full
+/// group/version/plural pass them through unchanged; otherwise `kind` (which may also be a
+/// plural or short name such as "deploy") is looked up via discovery for the active context.
+async fn resolve_target(
+    state: &AppState,
+    kind: &str,
+    group: Option<String>,
+    version: Option<String>,
+    plural: Option<String>,
+    cluster_scoped: Option<bool>,
+) -> Result<(Client, String, ApiResource, bool), String> {
+    let (client, ns, ctx) = state
+        .client_manager
+        .get_active_client_and_context()
+        .await
+        .map_err(|e| sanitize_error_msg(e.to_string()))?;
+    let group = group.unwrap_or_default();
+
+    if let (Some(version), Some(plural)) = (version, plural) {
+        let ar = build_api_resource(&group, &version, kind, &plural);
+        return Ok((client, ns, ar, cluster_scoped.unwrap_or(false)));
+    }
+
+    let query = if group.is_empty() { kind.to_string() } else { format!("{}.{}", kind, group) };
+    let (ar, cluster_scoped) = state
+        .client_manager
+        .discovery
+        .resolve_api_resource(&client, &ctx, &query)
+        .await
+        .map_err(|e| sanitize_error_msg(e.to_string()))?;
+    Ok((client, ns, ar, cluster_scoped))
+}
+
 #[tauri::command]
 pub async fn get_resource_yaml(
-    group: String,
-    version: String,
     kind: String,
-    plural: String,
     name: String,
+    group: Option<String>,
+    version: Option<String>,
+    plural: Option<String>,
     cluster_scoped: Option<bool>,
     state: State<'_, AppState>,
 ) -> Result<String, String> {
-    let (client, ns) = state.client_manager.get_active_client().await.map_err(|e| sanitize_error_msg(e.to_string()))?;
-    EditingHandler::get_resource_yaml(&client, &ns, &name, &group, &version, &kind, &plural, cluster_scoped.unwrap_or(false))
+    let (client, ns, ar, cluster_scoped) = resolve_target(&state, &kind, group, version, plural, cluster_scoped).await?;
+    EditingHandler::get_resource_yaml(&client, &ns, &name, &ar, cluster_scoped)
         .await
         .map_err(|e| sanitize_error_msg(e.to_string()))
 }
 
 #[tauri::command]
 pub async fn update_resource_yaml(
-    group: String,
-    version: String,
     kind: String,
-    plural: String,
     name: String,
     yaml_content: String,
+    group: Option<String>,
+    version: Option<String>,
+    plural: Option<String>,
     cluster_scoped: Option<bool>,
     state: State<'_, AppState>,
 ) -> Result<(), String> {
-    let (client, ns) = state.client_manager.get_active_client().await.map_err(|e| sanitize_error_msg(e.to_string()))?;
-    EditingHandler::update_resource_yaml(&client, &ns, &name, &group, &version, &kind, &plural, &yaml_content, cluster_scoped.unwrap_or(false))
+    let (client, ns, ar, cluster_scoped) = resolve_target(&state, &kind, group, version, plural, cluster_scoped).await?;
+    EditingHandler::update_resource_yaml(&client, &ns, &name, &ar, &yaml_content, cluster_scoped)
+        .await
+        .map_err(|e| sanitize_error_msg(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn validate_resource_yaml(
+    yaml_content: String,
+    state: State<'_, AppState>,
+) -> Result<SchemaValidationResult, String> {
+    EditingHandler::validate_resource_yaml(&state.client_manager, &yaml_content)
+        .await
+        .map_err(|e| sanitize_error_msg(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn dry_run_resource_yaml(
+    kind: String,
+    name: String,
+    yaml_content: String,
+    group: Option<String>,
+    version: Option<String>,
+    plural: Option<String>,
+    cluster_scoped: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<DryRunResult, String> {
+    let (client, ns, ar, cluster_scoped) = resolve_target(&state, &kind, group, version, plural, cluster_scoped).await?;
+    EditingHandler::dry_run_resource_yaml(&client, &ns, &name, &ar, &yaml_content, cluster_scoped)
+        .await
+        .map_err(|e| sanitize_error_msg(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn apply_resource_yaml(
+    kind: String,
+    name: String,
+    yaml_content: String,
+    force: Option<bool>,
+    group: Option<String>,
+    version: Option<String>,
+    plural: Option<String>,
+    cluster_scoped: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<ApplyResult, String> {
+    let (client, ns, ar, cluster_scoped) = resolve_target(&state, &kind, group, version, plural, cluster_scoped).await?;
+    EditingHandler::apply_resource_yaml(&client, &ns, &name, &ar, &yaml_content, force.unwrap_or(false), cluster_scoped)
+        .await
+        .map_err(|e| sanitize_error_msg(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn apply_manifests(
+    yaml_content: String,
+    force: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<Vec<ManifestApplyResult>, String> {
+    EditingHandler::apply_manifests(&state.client_manager, &yaml_content, force.unwrap_or(false))
         .await
         .map_err(|e| sanitize_error_msg(e.to_string()))
 }
 
 #[tauri::command]
 pub async fn patch_resource(
-    group: String,
-    version: String,
     kind: String,
-    plural: String,
     name: String,
     patch_json: Value,
+    group: Option<String>,
+    version: Option<String>,
+    plural: Option<String>,
     cluster_scoped: Option<bool>,
     state: State<'_, AppState>,
 ) -> Result<(), String> {
-    let (client, ns) = state.client_manager.get_active_client().await.map_err(|e| sanitize_error_msg(e.to_string()))?;
-    EditingHandler::patch_resource(&client, &ns, &name, &group, &version, &kind, &plural, &patch_json, cluster_scoped.unwrap_or(false))
+    let (client, ns, ar, cluster_scoped) = resolve_target(&state, &kind, group, version, plural, cluster_scoped).await?;
+    EditingHandler::patch_resource(&client, &ns, &name, &ar, &patch_json, cluster_scoped)
+        .await
+        .map_err(|e| sanitize_error_msg(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn dry_run_patch_resource(
+    kind: String,
+    name: String,
+    patch_json: Value,
+    group: Option<String>,
+    version: Option<String>,
+    plural: Option<String>,
+    cluster_scoped: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<DryRunResult, String> {
+    let (client, ns, ar, cluster_scoped) = resolve_target(&state, &kind, group, version, plural, cluster_scoped).await?;
+    EditingHandler::dry_run_patch_resource(&client, &ns, &name, &ar, &patch_json, cluster_scoped)
         .await
         .map_err(|e| sanitize_error_msg(e.to_string()))
 }
 
 #[tauri::command]
 pub async fn delete_resource(
-    group: String,
-    version: String,
     kind: String,
-    plural: String,
     name: String,
+    group: Option<String>,
+    version: Option<String>,
+    plural: Option<String>,
     cluster_scoped: Option<bool>,
     state: State<'_, AppState>,
 ) -> Result<(), String> {
-    let (client, ns) = state.client_manager.get_active_client().await.map_err(|e| sanitize_error_msg(e.to_string()))?;
-    EditingHandler::delete_resource(&client, &ns, &name, &group, &version, &kind, &plural, cluster_scoped.unwrap_or(false))
+    let (client, ns, ar, cluster_scoped) = resolve_target(&state, &kind, group, version, plural, cluster_scoped).await?;
+    EditingHandler::delete_resource(&client, &ns, &name, &ar, cluster_scoped)
+        .await
+        .map_err(|e| sanitize_error_msg(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn get_field_ownership(
+    kind: String,
+    name: String,
+    group: Option<String>,
+    version: Option<String>,
+    plural: Option<String>,
+    cluster_scoped: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<FieldOwnershipInfo, String> {
+    let (client, ns, ar, cluster_scoped) = resolve_target(&state, &kind, group, version, plural, cluster_scoped).await?;
+    EditingHandler::get_field_ownership(&client, &ns, &name, &ar, cluster_scoped)
+        .await
+        .map_err(|e| sanitize_error_msg(e.to_string()))
+}
+
+#[tauri::command]
+pub async fn remove_finalizer(
+    kind: String,
+    name: String,
+    finalizer: String,
+    group: Option<String>,
+    version: Option<String>,
+    plural: Option<String>,
+    cluster_scoped: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let (client, ns, ar, cluster_scoped) = resolve_target(&state, &kind, group, version, plural, cluster_scoped).await?;
+    EditingHandler::remove_finalizer(&client, &ns, &name, &ar, cluster_scoped, &finalizer)
         .await
         .map_err(|e| sanitize_error_msg(e.to_string()))
 }
 
 #[tauri::command]
 pub async fn get_resource_detail(
-    group: String,
-    version: String,
     kind: String,
-    plural: String,
     name: String,
+    group: Option<String>,
+    version: Option<String>,
+    plural: Option<String>,
     cluster_scoped: Option<bool>,
     state: State<'_, AppState>,
 ) -> Result<GenericResourceDetailInfo, String> {
-    let (client, ns) = state.client_manager.get_active_client().await.map_err(|e| sanitize_error_msg(e.to_string()))?;
-    EditingHandler::get_generic_resource_detail(&client, &ns, &name, &group, &version, &kind, &plural, cluster_scoped.unwrap_or(false))
+    let (client, ns, ar, cluster_scoped) = resolve_target(&state, &kind, group, version, plural, cluster_scoped).await?;
+    EditingHandler::get_generic_resource_detail(&client, &ns, &name, &ar, cluster_scoped)
         .await
         .map_err(|e| sanitize_error_msg(e.to_string()))
 }
@@ -13,6 +13,7 @@ pub fn sanitize_error_msg(msg: String) -> String {
     }
     msg
 }
+pub mod bulk_commands;
 pub mod cluster_commands;
 pub mod config_commands;
 pub mod crd_commands;
@@ -25,4 +26,5 @@ pub mod newrelic_commands;
 pub mod panel_commands;
 pub mod portforward_commands;
 pub mod resource_commands;
+pub mod template_commands;
 pub mod watch_commands;
@@ -7,6 +7,7 @@ use k8s_openapi::api::apps::v1::Deployment;
 use k8s_openapi::api::core::v1::Secret;
 
 use crate::application::handlers::resource_handler::ResourceHandler;
+use crate::application::handlers::workload_handler::WorkloadHandler;
 use crate::domain::entities::*;
 use crate::interfaces::state::AppState;
 
@@ -60,6 +61,30 @@ pub async fn get_cronjobs(state: State<'_, AppState>) -> Result<Vec<CronJobInfo>
     ResourceHandler::list_cronjobs(&client, &ns).await.map_err(Into::into)
 }
 
+#[tauri::command]
+pub async fn get_hpas(state: State<'_, AppState>) -> Result<Vec<HpaInfo>, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    ResourceHandler::list_hpas(&client, &ns).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_pdbs(state: State<'_, AppState>) -> Result<Vec<PdbInfo>, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    ResourceHandler::list_pdbs(&client, &ns).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_resource_quotas(state: State<'_, AppState>) -> Result<Vec<ResourceQuotaInfo>, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    ResourceHandler::list_resource_quotas(&client, &ns).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_limit_ranges(state: State<'_, AppState>) -> Result<Vec<LimitRangeInfo>, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    ResourceHandler::list_limit_ranges(&client, &ns).await.map_err(Into::into)
+}
+
 #[tauri::command]
 pub async fn get_services(state: State<'_, AppState>) -> Result<Vec<ServiceInfo>, String> {
     let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
@@ -84,6 +109,12 @@ pub async fn get_ingresses(state: State<'_, AppState>) -> Result<Vec<IngressInfo
     ResourceHandler::list_ingresses(&client, &ns).await.map_err(Into::into)
 }
 
+#[tauri::command]
+pub async fn get_network_policies(state: State<'_, AppState>) -> Result<Vec<NetworkPolicyInfo>, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    ResourceHandler::list_network_policies(&client, &ns).await.map_err(Into::into)
+}
+
 #[tauri::command]
 pub async fn get_gateways(state: State<'_, AppState>) -> Result<Vec<GatewayInfo>, String> {
     let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
@@ -113,6 +144,44 @@ pub async fn get_secret_data(
         .map_err(Into::into)
 }
 
+#[tauri::command]
+pub async fn get_resource_table(
+    kind: String,
+    all_namespaces: Option<bool>,
+    label_selector: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<ResourceTableInfo, String> {
+    let (client, ns, ctx) = state.client_manager.get_active_client_and_context().await.map_err(String::from)?;
+    let resource = state.client_manager.discovery.resolve(&client, &ctx, &kind).await.map_err(String::from)?;
+    let namespace = if all_namespaces.unwrap_or(false) { None } else { Some(ns.as_str()) };
+    ResourceHandler::list_resource_table(&client, namespace, &resource, label_selector.as_deref())
+        .await
+        .map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_stuck_resources(
+    kinds: Option<Vec<String>>,
+    min_age_seconds: Option<i64>,
+    all_namespaces: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<Vec<StuckResourceInfo>, String> {
+    let (client, ns, ctx) = state.client_manager.get_active_client_and_context().await.map_err(String::from)?;
+    let discovery = &state.client_manager.discovery;
+    let resources = match kinds {
+        Some(kinds) => {
+            let mut resolved = Vec::with_capacity(kinds.len());
+            for kind in kinds {
+                resolved.push(discovery.resolve(&client, &ctx, &kind).await.map_err(String::from)?);
+            }
+            resolved
+        }
+        None => discovery.resources(&client, &ctx).await.map_err(String::from)?.to_vec(),
+    };
+    let namespace = if all_namespaces.unwrap_or(false) { None } else { Some(ns.as_str()) };
+    Ok(ResourceHandler::find_stuck_resources(&client, namespace, &resources, min_age_seconds.unwrap_or(300)).await)
+}
+
 #[tauri::command]
 pub async fn get_generic_resources(
     group: String,
@@ -147,14 +216,7 @@ pub async fn scale_deployment(
     state: State<'_, AppState>,
 ) -> Result<(), String> {
     let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
-    let api: Api<Deployment> = Api::namespaced(client, &ns);
-    let patch = serde_json::json!({
-        "spec": { "replicas": replicas }
-    });
-    api.patch(&name, &PatchParams::apply("k0"), &Patch::Merge(&patch))
-        .await
-        .map_err(|e| e.to_string())?;
-    Ok(())
+    WorkloadHandler::scale(&client, &ns, "Deployment", &name, replicas).await.map_err(Into::into)
 }
 
 #[tauri::command]
@@ -163,23 +225,124 @@ pub async fn restart_deployment(
     state: State<'_, AppState>,
 ) -> Result<(), String> {
     let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
-    let api: Api<Deployment> = Api::namespaced(client, &ns);
-    let now = chrono::Utc::now().to_rfc3339();
-    let patch = serde_json::json!({
-        "spec": {
-            "template": {
-                "metadata": {
-                    "annotations": {
-                        "kubectl.kubernetes.io/restartedAt": now
-                    }
-                }
-            }
-        }
-    });
-    api.patch(&name, &PatchParams::apply("k0"), &Patch::Merge(&patch))
-        .await
-        .map_err(|e| e.to_string())?;
-    Ok(())
+    WorkloadHandler::restart(&client, &ns, "Deployment", &name).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn scale_workload(
+    kind: String,
+    name: String,
+    replicas: i32,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::scale(&client, &ns, &kind, &name, replicas).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn restart_workload(
+    kind: String,
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::restart(&client, &ns, &kind, &name).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn set_statefulset_partition(
+    name: String,
+    partition: i32,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::set_statefulset_partition(&client, &ns, &name, partition).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn pause_deployment(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::set_deployment_paused(&client, &ns, &name, true).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn resume_deployment(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::set_deployment_paused(&client, &ns, &name, false).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn preview_rollout_undo(
+    name: String,
+    revision: Option<i64>,
+    state: State<'_, AppState>,
+) -> Result<RolloutUndoPreview, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::preview_rollout_undo(&client, &ns, &name, revision).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn rollout_undo(
+    name: String,
+    revision: Option<i64>,
+    state: State<'_, AppState>,
+) -> Result<RolloutUndoPreview, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::rollout_undo(&client, &ns, &name, revision).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn trigger_cronjob(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::trigger_cronjob(&client, &ns, &name).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn set_cronjob_suspend(
+    name: String,
+    suspend: bool,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::set_cronjob_suspend(&client, &ns, &name, suspend).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_cronjob_runs(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<CronJobRunInfo>, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::list_cronjob_runs(&client, &ns, &name).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn get_cronjob_schedule(
+    name: String,
+    count: Option<usize>,
+    state: State<'_, AppState>,
+) -> Result<CronScheduleInfo, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    let count = count.unwrap_or(5).clamp(1, 100);
+    WorkloadHandler::get_cronjob_schedule(&client, &ns, &name, count).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn rerun_job(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::rerun_job(&client, &ns, &name).await.map_err(Into::into)
 }
 
 #[tauri::command]
@@ -224,51 +387,54 @@ pub async fn update_deployment_resources(
     state: State<'_, AppState>,
 ) -> Result<(), String> {
     let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
-    let api: Api<Deployment> = Api::namespaced(client, &ns);
-    let dep = api.get(&name).await.map_err(|e| e.to_string())?;
-    let containers = dep.spec.as_ref()
-        .and_then(|s| s.template.spec.as_ref())
-        .map(|ps| &ps.containers)
-        .ok_or("No containers found")?;
-    let idx = containers.iter().position(|c| c.name == container_name)
-        .ok_or(format!("Container {} not found", container_name))?;
-
-    let mut requests = serde_json::Map::new();
-    if !requests_cpu.is_empty() { requests.insert("cpu".into(), serde_json::json!(requests_cpu)); }
-    if !requests_memory.is_empty() { requests.insert("memory".into(), serde_json::json!(requests_memory)); }
-
-    let mut limits = serde_json::Map::new();
-    if !limits_cpu.is_empty() { limits.insert("cpu".into(), serde_json::json!(limits_cpu)); }
-    if !limits_memory.is_empty() { limits.insert("memory".into(), serde_json::json!(limits_memory)); }
-
-    // Build container patches - need to set all containers
-    let container_patches: Vec<serde_json::Value> = containers.iter().enumerate().map(|(i, c)| {
-        if i == idx {
-            serde_json::json!({
-                "name": c.name,
-                "resources": {
-                    "requests": requests,
-                    "limits": limits,
-                }
-            })
-        } else {
-            serde_json::json!({ "name": c.name })
-        }
-    }).collect();
+    WorkloadHandler::update_resources(
+        &client, &ns, "Deployment", &name, &container_name,
+        &requests_cpu, &requests_memory, &limits_cpu, &limits_memory,
+    )
+    .await
+    .map_err(Into::into)
+}
 
-    let patch = serde_json::json!({
-        "spec": {
-            "template": {
-                "spec": {
-                    "containers": container_patches
-                }
-            }
-        }
-    });
-    api.patch(&name, &PatchParams::apply("k0"), &Patch::Merge(&patch))
-        .await
-        .map_err(|e| e.to_string())?;
-    Ok(())
+#[tauri::command]
+pub async fn update_workload_resources(
+    kind: String,
+    name: String,
+    container_name: String,
+    requests_cpu: String,
+    requests_memory: String,
+    limits_cpu: String,
+    limits_memory: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::update_resources(
+        &client, &ns, &kind, &name, &container_name,
+        &requests_cpu, &requests_memory, &limits_cpu, &limits_memory,
+    )
+    .await
+    .map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn preview_workload_edit(
+    kind: String,
+    name: String,
+    edit: WorkloadEdit,
+    state: State<'_, AppState>,
+) -> Result<WorkloadEditPreview, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::preview_workload_edit(&client, &ns, &kind, &name, &edit).await.map_err(Into::into)
+}
+
+#[tauri::command]
+pub async fn apply_workload_edit(
+    kind: String,
+    name: String,
+    edit: WorkloadEdit,
+    state: State<'_, AppState>,
+) -> Result<WorkloadEditPreview, String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+    WorkloadHandler::apply_workload_edit(&client, &ns, &kind, &name, &edit).await.map_err(Into::into)
 }
 
 #[tauri::command]
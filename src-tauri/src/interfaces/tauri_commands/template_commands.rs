@@ -0,0 +1,51 @@
+use std::collections::BTreeMap;
+use tauri::State;
+
+use crate::application::handlers::template_handler::TemplateHandler;
+use crate::domain::entities::{ManifestApplyResult, ResourceTemplate};
+use crate::interfaces::state::AppState;
+use crate::interfaces::tauri_commands::sanitize_error_msg;
+
+#[tauri::command]
+pub async fn get_templates(
+    state: State<'_, AppState>,
+) -> Result<Vec<ResourceTemplate>, String> {
+    TemplateHandler::list_templates(&state.config_db).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn save_template(
+    template: ResourceTemplate,
+    state: State<'_, AppState>,
+) -> Result<ResourceTemplate, String> {
+    TemplateHandler::save_template(&state.config_db, template).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn delete_template(
+    id: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    TemplateHandler::delete_template(&state.config_db, &id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn render_template(
+    id: String,
+    variables: BTreeMap<String, String>,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    TemplateHandler::render_template(&state.config_db, &id, &variables).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn create_from_template(
+    id: String,
+    variables: BTreeMap<String, String>,
+    dry_run: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<Vec<ManifestApplyResult>, String> {
+    TemplateHandler::create_from_template(&state.client_manager, &state.config_db, &id, &variables, dry_run.unwrap_or(false))
+        .await
+        .map_err(|e| sanitize_error_msg(e.to_string()))
+}
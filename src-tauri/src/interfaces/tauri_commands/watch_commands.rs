@@ -3,6 +3,7 @@ use std::process::Command;
 use tauri::State;
 
 use crate::infrastructure::watchers::pod_watcher::run_pod_watcher;
+use crate::infrastructure::watchers::rollout_watcher::run_rollout_watcher;
 use crate::interfaces::state::AppState;
 use crate::interfaces::tauri_commands::sanitize_error_msg;
 
@@ -45,6 +46,43 @@ pub async fn stop_watching_pods(
     Ok(())
 }
 
+#[tauri::command]
+pub async fn start_rollout_status(
+    session_id: String,
+    name: String,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let (client, ns) = state.client_manager.get_active_client().await.map_err(String::from)?;
+
+    let mut sessions = state.rollout_sessions.lock().await;
+    if let Some(h) = sessions.remove(&session_id) {
+        h.abort();
+    }
+    // The watcher ends on its own once the rollout completes, stalls or the deployment goes
+    // away; drop its session then. The lock is held until the handle is inserted below.
+    let sessions_ref = state.rollout_sessions.clone();
+    let id = session_id.clone();
+    let handle = tokio::spawn(async move {
+        run_rollout_watcher(client, ns, name, id.clone(), app_handle).await;
+        sessions_ref.lock().await.remove(&id);
+    });
+    sessions.insert(session_id, handle);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn stop_rollout_status(
+    session_id: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let mut sessions = state.rollout_sessions.lock().await;
+    if let Some(h) = sessions.remove(&session_id) {
+        h.abort();
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn delete_pod(
     name: String,
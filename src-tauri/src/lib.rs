@@ -21,7 +21,12 @@ pub fn run() {
             interfaces::tauri_commands::chat_commands::check_claude_cli,
             interfaces::tauri_commands::cluster_commands::get_contexts,
             interfaces::tauri_commands::cluster_commands::set_active_context,
+            interfaces::tauri_commands::cluster_commands::get_api_resources,
+            interfaces::tauri_commands::cluster_commands::resolve_api_resource,
             interfaces::tauri_commands::cluster_commands::get_namespaces,
+            interfaces::tauri_commands::cluster_commands::create_namespace,
+            interfaces::tauri_commands::cluster_commands::delete_namespace,
+            interfaces::tauri_commands::cluster_commands::get_namespace_detail,
             interfaces::tauri_commands::cluster_commands::set_active_namespace,
             interfaces::tauri_commands::resource_commands::get_pods,
             interfaces::tauri_commands::resource_commands::get_deployments,
@@ -31,6 +36,11 @@ pub fn run() {
             interfaces::tauri_commands::resource_commands::get_replication_controllers,
             interfaces::tauri_commands::resource_commands::get_jobs,
             interfaces::tauri_commands::resource_commands::get_cronjobs,
+            interfaces::tauri_commands::resource_commands::get_hpas,
+            interfaces::tauri_commands::resource_commands::get_pdbs,
+            interfaces::tauri_commands::resource_commands::get_resource_quotas,
+            interfaces::tauri_commands::resource_commands::get_limit_ranges,
+            interfaces::tauri_commands::resource_commands::get_network_policies,
             interfaces::tauri_commands::resource_commands::get_services,
             interfaces::tauri_commands::resource_commands::get_configmaps,
             interfaces::tauri_commands::resource_commands::get_secrets,
@@ -39,6 +49,8 @@ pub fn run() {
             interfaces::tauri_commands::resource_commands::get_secret_value,
             interfaces::tauri_commands::resource_commands::get_secret_data,
             interfaces::tauri_commands::resource_commands::get_generic_resources,
+            interfaces::tauri_commands::resource_commands::get_resource_table,
+            interfaces::tauri_commands::resource_commands::get_stuck_resources,
             interfaces::tauri_commands::resource_commands::get_image_history,
             interfaces::tauri_commands::resource_commands::get_external_secrets_for_deployment,
             interfaces::tauri_commands::resource_commands::force_sync_external_secret,
@@ -46,27 +58,75 @@ pub fn run() {
             interfaces::tauri_commands::resource_commands::get_dependency_graph,
             interfaces::tauri_commands::resource_commands::scale_deployment,
             interfaces::tauri_commands::resource_commands::restart_deployment,
+            interfaces::tauri_commands::resource_commands::scale_workload,
+            interfaces::tauri_commands::resource_commands::restart_workload,
+            interfaces::tauri_commands::resource_commands::set_statefulset_partition,
+            interfaces::tauri_commands::resource_commands::pause_deployment,
+            interfaces::tauri_commands::resource_commands::resume_deployment,
+            interfaces::tauri_commands::resource_commands::preview_rollout_undo,
+            interfaces::tauri_commands::resource_commands::rollout_undo,
+            interfaces::tauri_commands::resource_commands::trigger_cronjob,
+            interfaces::tauri_commands::resource_commands::set_cronjob_suspend,
+            interfaces::tauri_commands::resource_commands::get_cronjob_runs,
+            interfaces::tauri_commands::resource_commands::get_cronjob_schedule,
+            interfaces::tauri_commands::resource_commands::rerun_job,
             interfaces::tauri_commands::resource_commands::get_deployment_info,
             interfaces::tauri_commands::resource_commands::update_deployment_resources,
+            interfaces::tauri_commands::resource_commands::update_workload_resources,
+            interfaces::tauri_commands::resource_commands::preview_workload_edit,
+            interfaces::tauri_commands::resource_commands::apply_workload_edit,
             interfaces::tauri_commands::detail_commands::get_pod_detail,
+            interfaces::tauri_commands::detail_commands::get_deployment_detail,
+            interfaces::tauri_commands::detail_commands::get_job_detail,
+            interfaces::tauri_commands::detail_commands::get_service_detail,
             interfaces::tauri_commands::detail_commands::get_ingress_detail,
             interfaces::tauri_commands::detail_commands::get_gateway_detail,
             interfaces::tauri_commands::detail_commands::get_httproute_detail,
             interfaces::tauri_commands::detail_commands::get_grpcroute_detail,
+            interfaces::tauri_commands::detail_commands::get_hpa_detail,
+            interfaces::tauri_commands::detail_commands::get_pdb_detail,
+            interfaces::tauri_commands::detail_commands::get_resource_quota_detail,
+            interfaces::tauri_commands::detail_commands::get_limit_range_detail,
+            interfaces::tauri_commands::detail_commands::get_workload_blockers,
+            interfaces::tauri_commands::detail_commands::get_network_policy_detail,
+            interfaces::tauri_commands::detail_commands::analyze_network_reachability,
             interfaces::tauri_commands::watch_commands::delete_pod,
             interfaces::tauri_commands::watch_commands::start_watching_pods,
             interfaces::tauri_commands::watch_commands::stop_watching_pods,
+            interfaces::tauri_commands::watch_commands::start_rollout_status,
+            interfaces::tauri_commands::watch_commands::stop_rollout_status,
             interfaces::tauri_commands::watch_commands::exec_pod_shell,
             interfaces::tauri_commands::watch_commands::get_process_env,
             interfaces::tauri_commands::editing_commands::get_resource_yaml,
+            interfaces::tauri_commands::editing_commands::get_field_ownership,
             interfaces::tauri_commands::editing_commands::update_resource_yaml,
+            interfaces::tauri_commands::editing_commands::validate_resource_yaml,
+            interfaces::tauri_commands::editing_commands::dry_run_resource_yaml,
+            interfaces::tauri_commands::editing_commands::apply_resource_yaml,
+            interfaces::tauri_commands::editing_commands::apply_manifests,
             interfaces::tauri_commands::editing_commands::patch_resource,
+            interfaces::tauri_commands::editing_commands::dry_run_patch_resource,
             interfaces::tauri_commands::editing_commands::delete_resource,
+            interfaces::tauri_commands::editing_commands::remove_finalizer,
             interfaces::tauri_commands::editing_commands::get_resource_detail,
+            interfaces::tauri_commands::bulk_commands::delete_resources,
+            interfaces::tauri_commands::bulk_commands::patch_resources,
+            interfaces::tauri_commands::bulk_commands::restart_workloads,
+            interfaces::tauri_commands::bulk_commands::delete_pods,
+            interfaces::tauri_commands::bulk_commands::delete_resources_by_selector,
+            interfaces::tauri_commands::bulk_commands::delete_pods_by_selector,
+            interfaces::tauri_commands::bulk_commands::update_resource_metadata,
+            interfaces::tauri_commands::bulk_commands::update_resource_metadata_by_selector,
             interfaces::tauri_commands::panel_commands::start_log_stream,
             interfaces::tauri_commands::panel_commands::stop_log_stream,
             interfaces::tauri_commands::crd_commands::get_crds,
             interfaces::tauri_commands::crd_commands::get_crd_instances,
+            interfaces::tauri_commands::crd_commands::get_crd_schema,
+            interfaces::tauri_commands::template_commands::get_templates,
+            interfaces::tauri_commands::template_commands::save_template,
+            interfaces::tauri_commands::template_commands::delete_template,
+            interfaces::tauri_commands::template_commands::render_template,
+            interfaces::tauri_commands::template_commands::create_from_template,
             interfaces::tauri_commands::portforward_commands::start_port_forward,
             interfaces::tauri_commands::portforward_commands::stop_port_forward,
             interfaces::tauri_commands::portforward_commands::list_port_forwards,
@@ -39,6 +39,7 @@ interface ServiceGroup {
   name: string;
   svcType: string;
   endpointCount: number;
+  terminatingCount: number;
   endpointPods: EndpointPod[];
   deployments: DeployChild[];
 }
@@ -63,40 +64,23 @@ function buildNetworkGroups(
     edgesFrom.get(e.source)!.push({ target: e.target, label: e.label });
   }
 
-  // Build service groups - parse status: "svcType|epCount|epPod1,epPod2,...|deployName:ready/desired:podCount;..."
+  // Build service groups from the structured service info on each Service node
   const services: ServiceGroup[] = nodes
     .filter((n) => n.node_type === "Service")
     .map((svc) => {
-      const parts = svc.status.split("|");
-      const svcType = parts[0] || "ClusterIP";
-      const endpointCount = parseInt(parts[1]) || 0;
-      const epPodsStr = parts[2] || "";
-      const deploysStr = parts[3] || "";
-
-      const endpointPods: EndpointPod[] = epPodsStr
-        ? epPodsStr.split(",").filter(Boolean).map((name) => ({ name }))
-        : [];
-
-      const deployments: DeployChild[] = deploysStr
-        ? deploysStr.split(";").filter(Boolean).map((entry) => {
-            // format: "deployName:ready/desired|podCount"
-            const [name, info] = entry.split(":");
-            const [readyStr, podStr] = (info || "").split("|");
-            return {
-              name: name || "",
-              ready: readyStr || "0/0",
-              podCount: parseInt(podStr) || 0,
-            };
-          })
-        : [];
-
+      const info = svc.service;
       return {
         id: svc.id,
         name: svc.label,
-        svcType,
-        endpointCount,
-        endpointPods,
-        deployments,
+        svcType: info?.service_type || svc.status || "ClusterIP",
+        endpointCount: info?.endpoints.ready ?? 0,
+        terminatingCount: info?.endpoints.terminating ?? 0,
+        endpointPods: (info?.endpoint_pods ?? []).map((name) => ({ name })),
+        deployments: (info?.workloads ?? []).map((w) => ({
+          name: w.name,
+          ready: w.ready,
+          podCount: w.pod_count,
+        })),
       };
     });
 
@@ -167,6 +151,7 @@ function ServiceCardNode({ data }: NodeProps) {
     name: string;
     svcType: string;
     endpointCount: number;
+    terminatingCount: number;
     endpointPods: EndpointPod[];
     deployments: DeployChild[];
   };
@@ -208,6 +193,9 @@ function ServiceCardNode({ data }: NodeProps) {
             {d.endpointCount}
           </span>
           {" "}Endpoint{d.endpointCount !== 1 ? "s" : ""}
+          {d.terminatingCount > 0 && (
+            <span className="ml-1 text-amber-400">({d.terminatingCount} terminating)</span>
+          )}
         </button>
         {epExpanded && d.endpointPods.length > 0 && (
           <div className="ml-2 mt-0.5 space-y-0.5 max-h-32 overflow-y-auto">
@@ -391,6 +379,7 @@ export function NetworkOverview() {
           name: svc.name,
           svcType: svc.svcType,
           endpointCount: svc.endpointCount,
+          terminatingCount: svc.terminatingCount,
           endpointPods: svc.endpointPods,
           deployments: svc.deployments,
         },
@@ -1,4 +1,4 @@
-import { useState, useEffect, useCallback } from "react";
+import { useState, useEffect, useCallback, useMemo } from "react";
 import {
   Dialog,
   DialogContent,
@@ -9,12 +9,14 @@ import {
 } from "@/components/ui/dialog";
 import { Button } from "@/components/ui/button";
 import { YamlEditor } from "@/components/ui/yaml-editor";
-import { Loader2, AlertCircle, RefreshCw } from "lucide-react";
+import { Loader2, AlertCircle, RefreshCw, Users } from "lucide-react";
 import {
+  getFieldOwnership,
   getResourceYaml,
   updateResourceYaml,
   type ResourceCoordinates,
 } from "@/lib/tauri-commands";
+import type { FieldOwnershipInfo } from "@/types/k8s";
 
 interface YamlEditorDialogProps {
   open: boolean;
@@ -32,22 +34,35 @@ export function YamlEditorDialog({
   onSaved,
 }: YamlEditorDialogProps) {
   const [yaml, setYaml] = useState("");
+  const [loadedYaml, setLoadedYaml] = useState("");
   const [loading, setLoading] = useState(false);
   const [saving, setSaving] = useState(false);
   const [error, setError] = useState<string | null>(null);
+  const [showOwners, setShowOwners] = useState(false);
+  const [ownership, setOwnership] = useState<FieldOwnershipInfo | null>(null);
 
   const fetchYaml = useCallback(async () => {
     setLoading(true);
     setError(null);
     try {
-      const content = await getResourceYaml(resourceCoords, resourceName);
-      setYaml(content);
+      if (showOwners) {
+        // Same YAML as getResourceYaml, plus who owns each field
+        const info = await getFieldOwnership(resourceCoords, resourceName);
+        setOwnership(info);
+        setYaml(info.yaml);
+        setLoadedYaml(info.yaml);
+      } else {
+        const content = await getResourceYaml(resourceCoords, resourceName);
+        setOwnership(null);
+        setYaml(content);
+        setLoadedYaml(content);
+      }
     } catch (e) {
       setError(String(e));
     } finally {
       setLoading(false);
     }
-  }, [resourceCoords, resourceName]);
+  }, [resourceCoords, resourceName, showOwners]);
 
   useEffect(() => {
     if (open) {
@@ -55,6 +70,19 @@ export function YamlEditorDialog({
     }
   }, [open, fetchYaml]);
 
+  // Owner positions refer to the fetched YAML, so they're only shown until it is edited
+  const edited = yaml !== loadedYaml;
+  const lineMarkers = useMemo(() => {
+    if (!ownership || edited) return undefined;
+    const markers: Record<number, string> = {};
+    for (const f of ownership.fields) {
+      const via = f.subresource ? `${f.operation}, ${f.subresource}` : f.operation;
+      const entry = `${f.manager} (${via}): ${f.path}`;
+      markers[f.line] = markers[f.line] ? `${markers[f.line]}\n${entry}` : entry;
+    }
+    return markers;
+  }, [ownership, edited]);
+
   const handleSave = async () => {
     setSaving(true);
     setError(null);
@@ -90,9 +118,30 @@ export function YamlEditorDialog({
             value={yaml}
             onChange={setYaml}
             readOnly={saving}
+            lineMarkers={lineMarkers}
           />
         )}
 
+        {ownership && !loading && (
+          <div className="space-y-1 text-xs text-muted-foreground">
+            {edited ? (
+              <p>Field owners are hidden while the YAML has unsaved edits.</p>
+            ) : (
+              <p>Hover a dot in the gutter to see which field managers own that line.</p>
+            )}
+            <div className="flex flex-wrap gap-x-4 gap-y-1">
+              {ownership.managers.map((m) => (
+                <span key={`${m.manager}/${m.operation}/${m.subresource}`} title={m.time}>
+                  <span className="font-mono text-foreground">{m.manager}</span>{" "}
+                  {m.operation}
+                  {m.subresource && ` (${m.subresource})`} · {m.field_count} field
+                  {m.field_count !== 1 ? "s" : ""} · {m.age}
+                </span>
+              ))}
+            </div>
+          </div>
+        )}
+
         {error && (
           <div className="flex items-start gap-2 rounded-md border border-destructive/50 bg-destructive/10 p-3 text-sm text-destructive">
             <AlertCircle className="mt-0.5 h-4 w-4 shrink-0" />
@@ -114,6 +163,16 @@ export function YamlEditorDialog({
         )}
 
         <DialogFooter>
+          <Button
+            variant={showOwners ? "secondary" : "ghost"}
+            className="mr-auto"
+            onClick={() => setShowOwners(!showOwners)}
+            disabled={loading || saving || edited}
+            title={edited ? "Save your edits before toggling field owners" : undefined}
+          >
+            <Users className="h-4 w-4" />
+            Field owners
+          </Button>
           <Button
             variant="outline"
             onClick={() => onOpenChange(false)}
@@ -1,6 +1,6 @@
 import { useRef, useEffect } from "react";
-import { EditorView, keymap, lineNumbers, highlightActiveLine, highlightActiveLineGutter, drawSelection, rectangularSelection } from "@codemirror/view";
-import { EditorState } from "@codemirror/state";
+import { EditorView, keymap, lineNumbers, highlightActiveLine, highlightActiveLineGutter, drawSelection, rectangularSelection, gutter, GutterMarker } from "@codemirror/view";
+import { EditorState, Compartment } from "@codemirror/state";
 import { yaml } from "@codemirror/lang-yaml";
 import { oneDark } from "@codemirror/theme-one-dark";
 import { defaultKeymap, history, historyKeymap, indentWithTab } from "@codemirror/commands";
@@ -29,17 +29,54 @@ const k0Theme = EditorView.theme({
   "&.cm-focused .cm-selectionBackground, .cm-selectionBackground": {
     backgroundColor: "hsl(var(--primary) / 0.2) !important",
   },
+  ".cm-line-marker": {
+    color: "hsl(var(--primary))",
+    cursor: "help",
+    padding: "0 2px",
+  },
 }, { dark: true });
 
+class LineMarker extends GutterMarker {
+  constructor(readonly title: string) {
+    super();
+  }
+
+  eq(other: LineMarker) {
+    return other.title === this.title;
+  }
+
+  toDOM() {
+    const el = document.createElement("span");
+    el.className = "cm-line-marker";
+    el.title = this.title;
+    el.textContent = "\u25CF";
+    return el;
+  }
+}
+
+// Gutter with a dot on each marked line (1-based); hovering it shows the line's note
+function lineMarkerGutter(markers: Record<number, string> | undefined) {
+  if (!markers) return [];
+  return gutter({
+    class: "cm-line-marker-gutter",
+    lineMarker(view, line) {
+      const title = markers[view.state.doc.lineAt(line.from).number];
+      return title ? new LineMarker(title) : null;
+    },
+  });
+}
+
 interface YamlEditorProps {
   value: string;
   onChange: (value: string) => void;
   readOnly?: boolean;
+  lineMarkers?: Record<number, string>;
 }
 
-export function YamlEditor({ value, onChange, readOnly = false }: YamlEditorProps) {
+export function YamlEditor({ value, onChange, readOnly = false, lineMarkers }: YamlEditorProps) {
   const containerRef = useRef<HTMLDivElement>(null);
   const viewRef = useRef<EditorView | null>(null);
+  const markersRef = useRef(new Compartment());
   const onChangeRef = useRef(onChange);
   onChangeRef.current = onChange;
 
@@ -56,6 +93,7 @@ export function YamlEditor({ value, onChange, readOnly = false }: YamlEditorProp
     const state = EditorState.create({
       doc: value,
       extensions: [
+        markersRef.current.of(lineMarkerGutter(lineMarkers)),
         lineNumbers(),
         highlightActiveLineGutter(),
         history(),
@@ -114,6 +152,12 @@ export function YamlEditor({ value, onChange, readOnly = false }: YamlEditorProp
     }
   }, [value]);
 
+  useEffect(() => {
+    viewRef.current?.dispatch({
+      effects: markersRef.current.reconfigure(lineMarkerGutter(lineMarkers)),
+    });
+  }, [lineMarkers]);
+
   return (
     <div
       ref={containerRef}
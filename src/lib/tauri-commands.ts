@@ -44,6 +44,7 @@ import type {
   NodeMetrics,
   ActiveAlertsSummary,
   ContainerUsageSummary,
+  FieldOwnershipInfo,
 } from "@/types/k8s";
 
 // Contexts
@@ -122,6 +123,9 @@ export interface ResourceCoordinates {
 export const getResourceYaml = (coords: ResourceCoordinates, name: string) =>
   invoke<string>("get_resource_yaml", { ...coords, name });
 
+export const getFieldOwnership = (coords: ResourceCoordinates, name: string) =>
+  invoke<FieldOwnershipInfo>("get_field_ownership", { ...coords, name });
+
 export const updateResourceYaml = (
   coords: ResourceCoordinates,
   name: string,
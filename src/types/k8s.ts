@@ -32,6 +32,7 @@ export interface DeploymentInfo {
   up_to_date: number;
   available: number;
   age: string;
+  blocked_by: string[];
 }
 
 export interface DaemonSetInfo {
@@ -49,6 +50,7 @@ export interface StatefulSetInfo {
   namespace: string;
   ready: string;
   age: string;
+  blocked_by: string[];
 }
 
 export interface ReplicaSetInfo {
@@ -437,6 +439,34 @@ export interface GraphNode {
   label: string;
   node_type: string;
   status: string;
+  service: ServiceNodeInfo | null;
+}
+
+export interface ZoneEndpointInfo {
+  zone: string;
+  ready: number;
+  serving: number;
+  terminating: number;
+}
+
+export interface EndpointHealthInfo {
+  ready: number;
+  serving: number;
+  terminating: number;
+  zones: ZoneEndpointInfo[];
+}
+
+export interface ServiceNodeInfo {
+  service_type: string;
+  endpoint_pods: string[];
+  endpoints: EndpointHealthInfo;
+  workloads: ServiceNodeWorkload[];
+}
+
+export interface ServiceNodeWorkload {
+  name: string;
+  ready: string;
+  pod_count: number;
 }
 
 export interface GraphEdge {
@@ -638,6 +668,33 @@ export interface ContainerUsageSummary {
   containers: ContainerUsage[];
 }
 
+// Field ownership types
+export interface FieldManagerInfo {
+  manager: string;
+  operation: string;
+  api_version: string;
+  subresource: string;
+  time: string;
+  age: string;
+  field_count: number;
+}
+
+export interface FieldOwnerInfo {
+  path: string;
+  manager: string;
+  operation: string;
+  subresource: string;
+  time: string;
+  line: number;
+  column: number;
+}
+
+export interface FieldOwnershipInfo {
+  yaml: string;
+  managers: FieldManagerInfo[];
+  fields: FieldOwnerInfo[];
+}
+
 // Minikube types
 export interface MinikubeProfile {
   name: string;